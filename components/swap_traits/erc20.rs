@@ -1,5 +1,7 @@
-use ink_env::AccountId;
+use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+use ink_env::{AccountId, DefaultEnvironment};
 use ink_lang as ink;
+use ink_prelude::string::String;
 
 type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
 
@@ -9,6 +11,10 @@ pub enum Erc20Error {
     InsufficientBalance,
     InsufficientAllowance,
     BalanceOverflowOrUnderflow,
+    /// A permit was submitted after its deadline.
+    Expired,
+    /// A permit was not signed by the owner.
+    InvalidSignature,
 }
 
 pub type Erc20Result<T> = core::result::Result<T, Erc20Error>;
@@ -37,6 +43,101 @@ pub trait Erc20 {
     fn approve(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()>;
 
     /// Transfers `value` tokens on the behalf of `from` to the account `to`.
+    ///
+    /// Deducts `value` from the caller's allowance,
+    /// unless the allowance is `Balance::MAX`, which is infinite.
     #[ink(message)]
     fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> Erc20Result<()>;
+
+    /// Atomically increases the allowance of `spender` over the caller's
+    /// tokens by `value`.
+    ///
+    /// Unlike `approve`, this can't be front-run by `spender`
+    /// spending the old allowance before it is replaced.
+    #[ink(message)]
+    fn increase_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()>;
+
+    /// Atomically decreases the allowance of `spender` over the caller's
+    /// tokens by `value`.
+    ///
+    /// Fails with `InsufficientAllowance` if the allowance is less than `value`.
+    #[ink(message)]
+    fn decrease_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()>;
+}
+
+/// The optional metadata of an [`Erc20`] token.
+#[ink::trait_definition]
+pub trait Erc20Metadata {
+    /// Returns the name of the token.
+    #[ink(message)]
+    fn name(&self) -> String;
+
+    /// Returns the symbol of the token, usually a shorter version of the name.
+    #[ink(message)]
+    fn symbol(&self) -> String;
+
+    /// Returns the number of decimals used to display token amounts.
+    ///
+    /// E.g. with 18 decimals, a balance of `1_000_000_000_000_000_000`
+    /// is displayed as `1`.
+    #[ink(message)]
+    fn decimals(&self) -> u8;
+}
+
+/// Calls the `Erc20` messages of a deployed token contract.
+///
+/// Calls that fail in the environment, e.g. because the callee trapped,
+/// panic and thereby revert the calling contract too.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Erc20Ref {
+    account_id: AccountId,
+}
+
+impl Erc20Ref {
+    pub fn new(account_id: AccountId) -> Self {
+        Self { account_id }
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    pub fn balance_of(&self, owner: AccountId) -> Balance {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("Erc20::balance_of")))
+                    .push_arg(owner),
+            )
+            .returns::<Balance>()
+            .fire()
+            .unwrap_or_else(|error| panic!("Erc20::balance_of failed: {:?}", error))
+    }
+
+    pub fn transfer_from(&self, from: AccountId, to: AccountId, value: Balance) -> Erc20Result<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("Erc20::transfer_from")))
+                    .push_arg(from)
+                    .push_arg(to)
+                    .push_arg(value),
+            )
+            .returns::<Erc20Result<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("Erc20::transfer_from failed: {:?}", error))
+    }
+
+    pub fn transfer(&self, to: AccountId, value: Balance) -> Erc20Result<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("Erc20::transfer")))
+                    .push_arg(to)
+                    .push_arg(value),
+            )
+            .returns::<Erc20Result<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("Erc20::transfer failed: {:?}", error))
+    }
 }
@@ -9,11 +9,25 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod erc20;
+mod ownable;
+mod revert;
 mod uniswap_v2_callee;
 mod uniswap_v2_factory;
 mod uniswap_v2_pair;
+mod uniswap_v2_router;
+mod upgradeable;
+mod uq112x112;
+mod wrapped_native;
 
 pub use erc20::*;
+pub use ownable::*;
+pub use revert::*;
 pub use uniswap_v2_callee::*;
 pub use uniswap_v2_factory::*;
 pub use uniswap_v2_pair::*;
+pub use uniswap_v2_router::*;
+pub use upgradeable::*;
+pub use uq112x112::*;
+pub use wrapped_native::*;
+
+pub use primitive_types::U256;
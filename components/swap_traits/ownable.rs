@@ -0,0 +1,178 @@
+//! Ownership that is handed over in two steps, so that it can't be lost by
+//! proposing the wrong account: the new owner must accept it.
+//!
+//! # References
+//!
+//! - <https://docs.openzeppelin.com/contracts/4.x/api/access#Ownable2Step>
+
+use ink_env::AccountId;
+use ink_lang as ink;
+use ink_storage::traits::{SpreadAllocate, SpreadLayout};
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum OwnableError {
+    /// The caller isn't the owner.
+    CallerIsNotOwner,
+    /// The caller isn't the proposed owner.
+    CallerIsNotPendingOwner,
+}
+
+pub type OwnableResult<T> = core::result::Result<T, OwnableError>;
+
+#[ink::trait_definition]
+pub trait Ownable2Step {
+    /// Returns the owner, or `None` once ownership has been renounced.
+    #[ink(message)]
+    fn owner(&self) -> Option<AccountId>;
+
+    /// Returns the account proposed as the next owner, if any.
+    #[ink(message)]
+    fn pending_owner(&self) -> Option<AccountId>;
+
+    /// Proposes `new_owner` as the next owner, replacing any earlier proposal.
+    ///
+    /// Only the owner may propose.
+    #[ink(message)]
+    fn propose_owner(&mut self, new_owner: AccountId) -> OwnableResult<()>;
+
+    /// Makes the caller the owner.
+    ///
+    /// Only the proposed owner may accept.
+    #[ink(message)]
+    fn accept_ownership(&mut self) -> OwnableResult<()>;
+
+    /// Leaves the contract without an owner, for good.
+    ///
+    /// Only the owner may renounce.
+    #[ink(message)]
+    fn renounce_ownership(&mut self) -> OwnableResult<()>;
+}
+
+/// The state behind [`Ownable2Step`], for contracts to embed in their storage.
+///
+/// Like `Erc20Data`, it leaves emitting events to the embedding contract.
+#[derive(Debug, Default, SpreadAllocate, SpreadLayout)]
+#[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+pub struct Ownable2StepData {
+    owner: Option<AccountId>,
+    pending_owner: Option<AccountId>,
+}
+
+impl Ownable2StepData {
+    /// Sets the first owner. Must be called from the constructor.
+    pub fn init(&mut self, owner: AccountId) {
+        self.owner = Some(owner);
+    }
+
+    pub fn owner(&self) -> Option<AccountId> {
+        self.owner
+    }
+
+    pub fn pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner
+    }
+
+    pub fn ensure_owner(&self, caller: &AccountId) -> OwnableResult<()> {
+        if self.owner != Some(*caller) {
+            return Err(OwnableError::CallerIsNotOwner);
+        }
+        Ok(())
+    }
+
+    pub fn propose_owner(&mut self, caller: &AccountId, new_owner: AccountId) -> OwnableResult<()> {
+        self.ensure_owner(caller)?;
+        self.pending_owner = Some(new_owner);
+        Ok(())
+    }
+
+    /// Returns the previous owner.
+    pub fn accept_ownership(&mut self, caller: &AccountId) -> OwnableResult<Option<AccountId>> {
+        if self.pending_owner != Some(*caller) {
+            return Err(OwnableError::CallerIsNotPendingOwner);
+        }
+        self.pending_owner = None;
+        Ok(self.owner.replace(*caller))
+    }
+
+    /// Also withdraws any proposal.
+    pub fn renounce_ownership(&mut self, caller: &AccountId) -> OwnableResult<()> {
+        self.ensure_owner(caller)?;
+        self.owner = None;
+        self.pending_owner = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(byte: u8) -> AccountId {
+        AccountId::from([byte; 32])
+    }
+
+    /// Ownership held by `account(1)`.
+    fn owned() -> Ownable2StepData {
+        let mut ownable = Ownable2StepData::default();
+        ownable.init(account(1));
+        ownable
+    }
+
+    #[test]
+    fn only_owner_proposes() {
+        let mut ownable = owned();
+        assert_eq!(
+            ownable.propose_owner(&account(2), account(2)),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(ownable.pending_owner(), None);
+        assert_eq!(ownable.propose_owner(&account(1), account(2)), Ok(()));
+        assert_eq!(ownable.pending_owner(), Some(account(2)));
+    }
+
+    #[test]
+    fn only_pending_owner_accepts() {
+        let mut ownable = owned();
+        ownable.propose_owner(&account(1), account(2)).unwrap();
+        assert_eq!(
+            ownable.accept_ownership(&account(3)),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        assert_eq!(
+            ownable.accept_ownership(&account(1)),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        assert_eq!(ownable.owner(), Some(account(1)));
+    }
+
+    #[test]
+    fn accepting_transfers_ownership() {
+        let mut ownable = owned();
+        ownable.propose_owner(&account(1), account(2)).unwrap();
+        assert_eq!(ownable.accept_ownership(&account(2)), Ok(Some(account(1))));
+        assert_eq!(ownable.owner(), Some(account(2)));
+        assert_eq!(ownable.pending_owner(), None);
+        assert_eq!(
+            ownable.ensure_owner(&account(1)),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+    }
+
+    #[test]
+    fn renouncing_clears_owner_and_proposal() {
+        let mut ownable = owned();
+        ownable.propose_owner(&account(1), account(2)).unwrap();
+        assert_eq!(
+            ownable.renounce_ownership(&account(2)),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(ownable.renounce_ownership(&account(1)), Ok(()));
+        assert_eq!(ownable.owner(), None);
+        assert_eq!(ownable.pending_owner(), None);
+        assert_eq!(
+            ownable.accept_ownership(&account(2)),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+    }
+}
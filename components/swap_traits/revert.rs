@@ -0,0 +1,16 @@
+//! Failing a call without keeping any of its effects.
+
+use ink_env::ReturnFlags;
+
+/// Ends the call, returning `Err(error)` and reverting all of its effects.
+///
+/// Returning an error from an ink! message keeps the changes made before it,
+/// including those made by calls into other contracts, so errors found after
+/// such changes must revert instead. The encoded result is the same whatever
+/// the `Ok` type of the message, so `error` must be of its error type.
+pub fn revert<E: scale::Encode, T>(error: E) -> T {
+    ink_env::return_value(
+        ReturnFlags::default().set_reverted(true),
+        &Err::<(), E>(error),
+    )
+}
@@ -7,12 +7,56 @@
 //! - <https://github.com/Uniswap/v2-core/blob/master/contracts/interfaces/IUniswapV2Callee.sol>
 //! - <https://docs.uniswap.org/protocol/V2/guides/smart-contract-integration/using-flash-swaps>
 
-use ink_env::AccountId;
+use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+use ink_env::{AccountId, DefaultEnvironment};
 use ink_lang as ink;
 use ink_prelude::vec::Vec;
 
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
 #[ink::trait_definition]
 pub trait IUniswapV2Callee {
     #[ink(message)]
-    fn uniswap_v2_call(&mut self, sender: AccountId, amount0: u64, amount1: u64, data: Vec<u8>);
+    fn uniswap_v2_call(
+        &mut self,
+        sender: AccountId,
+        amount0: Balance,
+        amount1: Balance,
+        data: Vec<u8>,
+    );
+}
+
+/// Calls the `IUniswapV2Callee` messages of a deployed contract.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IUniswapV2CalleeRef {
+    account_id: AccountId,
+}
+
+impl IUniswapV2CalleeRef {
+    pub fn new(account_id: AccountId) -> Self {
+        Self { account_id }
+    }
+
+    pub fn uniswap_v2_call(
+        &self,
+        sender: AccountId,
+        amount0: Balance,
+        amount1: Balance,
+        data: Vec<u8>,
+    ) {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IUniswapV2Callee::uniswap_v2_call"
+                )))
+                .push_arg(sender)
+                .push_arg(amount0)
+                .push_arg(amount1)
+                .push_arg(data),
+            )
+            .returns::<()>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Callee::uniswap_v2_call failed: {:?}", error))
+    }
 }
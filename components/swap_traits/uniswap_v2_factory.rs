@@ -5,9 +5,12 @@
 //! - <https://github.com/Uniswap/v2-core/blob/master/contracts/interfaces/IUniswapV2Factory.sol>
 //! - <https://docs.uniswap.org/protocol/V2/reference/smart-contracts/factory>
 
-use ink_env::AccountId;
+use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+use ink_env::{AccountId, DefaultEnvironment};
 use ink_lang as ink;
 
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
 // Note on access modifier (https://www.c-sharpcorner.com/article/variables-and-types-in-solidity/)
 
 // View functions are read only functions and do not modify the state of the block chain.
@@ -19,52 +22,186 @@ use ink_lang as ink;
 //  message with a &mut self receiver may mutate the contract’s storage.
 // (https://paritytech.github.io/ink/ink_lang/attr.contract.html#:~:text=Note%3A-,An,-ink!%20message%20with)
 
-//TODO: Add this inside the contract
-//     #[ink(event)]
-//     pub struct PairCreated {
-//         #[ink(topic)]
-//         token: Option<AccountId>,
-
-//         #[ink(topic)]
-//         token1: Option<AccountId>,
-
-//         pair: Option<AccountId>,
-
-//         log_value: u32 //1 for the first pair created, 2 for the second
-//     }
-
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum UniswapFactoryError {
     IdenticalAddress,
     ZeroAddress,
     PairExists,
+    /// The caller may not call the message.
+    Forbidden,
+    /// Fees must be less than the fee denominator.
+    InvalidFees,
 }
 
 pub type UniswapFactoryResult<T> = core::result::Result<T, UniswapFactoryError>;
 
-
 #[ink::trait_definition]
 pub trait IUniswapV2Factory {
     #[ink(message)]
     fn fee_to(&self) -> AccountId;
 
+    /// Returns the account that may set `fee_to` and the fees: the owner,
+    /// as in `Ownable2Step`, or `None` once ownership has been renounced.
     #[ink(message)]
-    fn fee_to_setter(&self) -> AccountId;
+    fn fee_to_setter(&self) -> Option<AccountId>;
 
+    /// Returns the pair for two tokens, in either order, if it exists.
     #[ink(message)]
-    fn get_pair(&self, toekn_a: AccountId, token_b: AccountId) -> AccountId;
+    fn get_pair(&self, token_a: AccountId, token_b: AccountId) -> Option<AccountId>;
 
+    /// Returns the pair created `log_value`th, counting from zero.
     #[ink(message)]
-    fn all_pairs(&self, log_value: u64) -> AccountId;
+    fn all_pairs(&self, log_value: u64) -> Option<AccountId>;
 
     #[ink(message)]
     fn all_pairs_length(&self) -> u64;
 
     #[ink(message)]
-    fn create_pair(&mut self, token_a: AccountId, token_b: AccountId) -> AccountId;
+    fn create_pair(
+        &mut self,
+        token_a: AccountId,
+        token_b: AccountId,
+    ) -> UniswapFactoryResult<AccountId>;
+
+    /// Returns the fee swaps pay, in thousandths of their input.
+    #[ink(message)]
+    fn fees(&self) -> Balance;
+
+    /// Sets the fee swaps pay, in thousandths of their input,
+    /// for all pairs at once.
+    ///
+    /// Only `fee_to_setter` may change the fees.
+    #[ink(message)]
+    fn set_fees(&mut self, fees: Balance) -> UniswapFactoryResult<()>;
+
+    /// Sets the account that receives the protocol fee.
+    ///
+    /// Only `fee_to_setter` may change it. `fee_to_setter` itself
+    /// changes hands through `Ownable2Step`.
+    #[ink(message)]
+    fn set_fee_to(&mut self, address: AccountId) -> UniswapFactoryResult<()>;
+
+    /// Returns the account that may pause and unpause pairs.
+    #[ink(message)]
+    fn guardian(&self) -> AccountId;
 
+    /// Sets the guardian.
+    ///
+    /// Only `fee_to_setter` may change it.
     #[ink(message)]
-    fn set_fee_to(&mut self, address: AccountId);
+    fn set_guardian(&mut self, guardian: AccountId) -> UniswapFactoryResult<()>;
 
+    /// Returns whether `pair` is paused, on its own or with all pairs.
+    ///
+    /// Paused pairs refuse to `swap` and `mint`, but still `burn`,
+    /// so that liquidity providers can always exit.
     #[ink(message)]
-    fn set_fee_to_setter(&mut self, address: AccountId);
+    fn is_paused(&self, pair: AccountId) -> bool;
+
+    /// Pauses or unpauses one pair.
+    ///
+    /// Only the guardian may pause. Unpausing a pair doesn't
+    /// unpause it while all pairs are paused.
+    #[ink(message)]
+    fn set_paused(&mut self, pair: AccountId, paused: bool) -> UniswapFactoryResult<()>;
+
+    /// Pauses or unpauses all pairs at once.
+    ///
+    /// Only the guardian may pause.
+    #[ink(message)]
+    fn set_all_paused(&mut self, paused: bool) -> UniswapFactoryResult<()>;
+}
+
+/// Calls the `IUniswapV2Factory` messages of a deployed factory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IUniswapV2FactoryRef {
+    account_id: AccountId,
+}
+
+impl IUniswapV2FactoryRef {
+    pub fn new(account_id: AccountId) -> Self {
+        Self { account_id }
+    }
+
+    pub fn fee_to(&self) -> AccountId {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Factory::fee_to"
+            ))))
+            .returns::<AccountId>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::fee_to failed: {:?}", error))
+    }
+
+    pub fn fee_to_setter(&self) -> Option<AccountId> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Factory::fee_to_setter"
+            ))))
+            .returns::<Option<AccountId>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::fee_to_setter failed: {:?}", error))
+    }
+
+    pub fn fees(&self) -> Balance {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Factory::fees"
+            ))))
+            .returns::<Balance>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::fees failed: {:?}", error))
+    }
+
+    pub fn is_paused(&self, pair: AccountId) -> bool {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IUniswapV2Factory::is_paused"
+                )))
+                .push_arg(pair),
+            )
+            .returns::<bool>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::is_paused failed: {:?}", error))
+    }
+
+    pub fn get_pair(&self, token_a: AccountId, token_b: AccountId) -> Option<AccountId> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IUniswapV2Factory::get_pair"
+                )))
+                .push_arg(token_a)
+                .push_arg(token_b),
+            )
+            .returns::<Option<AccountId>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::get_pair failed: {:?}", error))
+    }
+
+    pub fn create_pair(
+        &self,
+        token_a: AccountId,
+        token_b: AccountId,
+    ) -> UniswapFactoryResult<AccountId> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IUniswapV2Factory::create_pair"
+                )))
+                .push_arg(token_a)
+                .push_arg(token_b),
+            )
+            .returns::<UniswapFactoryResult<AccountId>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Factory::create_pair failed: {:?}", error))
+    }
 }
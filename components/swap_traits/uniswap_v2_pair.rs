@@ -7,32 +7,46 @@
 //! - <https://docs.uniswap.org/protocol/V2/reference/smart-contracts/Pair-ERC-20>
 //! - <https://github.com/paritytech/ink/blob/master/examples/trait-erc20/lib.rs>
 
-use ink_env::AccountId;
+use crate::{Erc20Error, UQ112x112, U256};
+use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+use ink_env::{AccountId, DefaultEnvironment};
 use ink_lang as ink;
 use ink_prelude::string::String;
 use ink_prelude::vec::Vec;
 
-// #[ink(event)]
-// struct Approval{
-//     #[ink(topic)]
-//     owner: AccountId,
-
-//     #[ink(topic)]
-//     spender: AccountId,
-
-//     value: Balance,
-// }
-
-// #[ink(event)]
-// struct Transfer{
-//     #[ink(topic)]
-//     from: AccountId,
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UniswapPairError {
+    InsufficientLiquidity,
+    InsufficientOutputAmount,
+    InsufficientInputAmount,
+    InvalidTo,
+    K,
+    /// `mint`, `burn`, `swap`, `skim` or `sync` was re-entered.
+    Locked,
+    /// The factory's guardian paused the pair.
+    Paused,
+    Expired,
+    InvalidSignature,
+    Forbidden,
+    Overflow,
+    /// Moving liquidity tokens, or one of the pair's tokens, failed.
+    Erc20(Erc20Error),
+}
 
-//     #[ink(topic)]
-//     to: AccountId,
+impl From<Erc20Error> for UniswapPairError {
+    fn from(error: Erc20Error) -> Self {
+        match error {
+            Erc20Error::Expired => UniswapPairError::Expired,
+            Erc20Error::InvalidSignature => UniswapPairError::InvalidSignature,
+            error => UniswapPairError::Erc20(error),
+        }
+    }
+}
 
-//     value: Balance,
-// }
+pub type UniswapPairResult<T> = core::result::Result<T, UniswapPairError>;
 
 #[ink::trait_definition]
 pub trait IUniswapV2Pair {
@@ -46,49 +60,58 @@ pub trait IUniswapV2Pair {
     fn decimals(&self) -> u8;
 
     #[ink(message)]
-    fn total_supply(&self) -> u64;
+    fn total_supply(&self) -> Balance;
 
     #[ink(message)]
-    fn balance_of(&self, owner: AccountId) -> u64;
+    fn balance_of(&self, owner: AccountId) -> Balance;
 
     #[ink(message)]
-    fn allowance(&self, owner: AccountId, spender: AccountId) -> u64;
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance;
 
     #[ink(message)]
-    fn approve(&mut self, spender: AccountId, value: u64) -> bool;
+    fn approve(&mut self, spender: AccountId, value: Balance) -> UniswapPairResult<()>;
 
     #[ink(message)]
-    fn transfer(&mut self, to: AccountId, value: u64) -> bool;
+    fn transfer(&mut self, to: AccountId, value: Balance) -> UniswapPairResult<()>;
 
+    /// Like `Erc20::transfer_from`, an allowance of `Balance::MAX` is infinite.
     #[ink(message)]
-    fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u64) -> bool;
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> UniswapPairResult<()>;
 
+    /// The hash binding permits to this contract.
     #[ink(message)]
-    fn domain_separator(&self) -> Vec<u8>;
+    fn domain_separator(&self) -> [u8; 32];
 
     #[ink(message)]
-    fn permit_typehash(&self) -> Vec<u8>;
+    fn permit_typehash(&self) -> [u8; 32];
 
+    /// The nonce `owner`'s next permit must be signed with.
     #[ink(message)]
     fn nonces(&self, owner: AccountId) -> u64;
 
-    // function permit(address owner, address spender, uint value, uint deadline, uint8 v, bytes32 r, bytes32 s) external;
+    /// Approves `spender` to spend `value` of `owner`'s liquidity tokens,
+    /// authorized by `owner`'s ECDSA signature `(v, r, s)` instead of a call.
+    ///
+    /// `deadline` is a block timestamp in milliseconds.
     #[ink(message)]
     fn permit(
         &mut self,
         owner: AccountId,
         spender: AccountId,
-        value: u64,
+        value: Balance,
         deadline: u64,
         v: u8,
-        r: Vec<u8>,
-        s: Vec<u8>,
-    );
-
-    //event Mint, Burn, Swap, Sync
+        r: [u8; 32],
+        s: [u8; 32],
+    ) -> UniswapPairResult<()>;
 
     #[ink(message)]
-    fn minimum_liquidity(&self) -> u64;
+    fn minimum_liquidity(&self) -> Balance;
 
     #[ink(message)]
     fn factory(&self) -> AccountId;
@@ -100,36 +123,176 @@ pub trait IUniswapV2Pair {
     fn token1(&self) -> AccountId;
 
     // function getReserves() external view returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast);
+    /// Returns the reserves, which never exceed 112 bits,
+    /// and the time of their last update in seconds, modulo 2^32.
     #[ink(message)]
-    fn get_reserves(&self) -> (u64, u64, u32);
+    fn get_reserves(&self) -> (Balance, Balance, u32);
 
     #[ink(message)]
-    fn price_0_cumulative_last(&self) -> u64;
+    fn price_0_cumulative_last(&self) -> UQ112x112;
 
     #[ink(message)]
-    fn price_1_cumulative_last(&self) -> u64;
+    fn price_1_cumulative_last(&self) -> UQ112x112;
 
+    /// Returns `reserve0 * reserve1` as of the last liquidity event,
+    /// if the protocol fee was on.
     #[ink(message)]
-    fn k_last(&self) -> u64;
+    fn k_last(&self) -> U256;
 
     #[ink(message)]
-    fn mint(&mut self, to: AccountId) -> u64;
+    fn mint(&mut self, to: AccountId) -> UniswapPairResult<Balance>;
 
     // function burn(address to) external returns (uint amount0, uint amount1);
     #[ink(message)]
-    fn burn(&mut self) -> (u64, u64);
+    fn burn(&mut self, to: AccountId) -> UniswapPairResult<(Balance, Balance)>;
 
     // function swap(uint amount0Out, uint amount1Out, address to, bytes calldata data) external;
     #[ink(message)]
-    fn swap(&mut self, amount0_out: u64, amount1_out: u64, to: AccountId, data: Vec<u8>);
+    fn swap(
+        &mut self,
+        amount0_out: Balance,
+        amount1_out: Balance,
+        to: AccountId,
+        data: Vec<u8>,
+    ) -> UniswapPairResult<()>;
 
     #[ink(message)]
-    fn skim(&mut self, to: AccountId);
+    fn skim(&mut self, to: AccountId) -> UniswapPairResult<()>;
 
     #[ink(message)]
-    fn sync(&mut self);
+    fn sync(&mut self) -> UniswapPairResult<()>;
 
     // function initialize(address, address) external;
     #[ink(message)]
-    fn initialize(&mut self, address1: AccountId, address2: AccountId);
+    fn initialize(&mut self, address1: AccountId, address2: AccountId) -> UniswapPairResult<()>;
+}
+
+/// Calls the `IUniswapV2Pair` messages of a deployed pair.
+///
+/// Messages returning a `UniswapPairResult` decode the error even if the pair
+/// reverted, so callers that changed state before the call must revert too.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IUniswapV2PairRef {
+    account_id: AccountId,
+}
+
+impl IUniswapV2PairRef {
+    pub fn new(account_id: AccountId) -> Self {
+        Self { account_id }
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    pub fn token0(&self) -> AccountId {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Pair::token0"
+            ))))
+            .returns::<AccountId>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::token0 failed: {:?}", error))
+    }
+
+    pub fn get_reserves(&self) -> (Balance, Balance, u32) {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Pair::get_reserves"
+            ))))
+            .returns::<(Balance, Balance, u32)>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::get_reserves failed: {:?}", error))
+    }
+
+    pub fn transfer_from(
+        &self,
+        from: AccountId,
+        to: AccountId,
+        value: Balance,
+    ) -> UniswapPairResult<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IUniswapV2Pair::transfer_from"
+                )))
+                .push_arg(from)
+                .push_arg(to)
+                .push_arg(value),
+            )
+            .returns::<UniswapPairResult<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::transfer_from failed: {:?}", error))
+    }
+
+    pub fn mint(&self, to: AccountId) -> UniswapPairResult<Balance> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("IUniswapV2Pair::mint")))
+                    .push_arg(to),
+            )
+            .returns::<UniswapPairResult<Balance>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::mint failed: {:?}", error))
+    }
+
+    pub fn burn(&self, to: AccountId) -> UniswapPairResult<(Balance, Balance)> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("IUniswapV2Pair::burn")))
+                    .push_arg(to),
+            )
+            .returns::<UniswapPairResult<(Balance, Balance)>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::burn failed: {:?}", error))
+    }
+
+    pub fn swap(
+        &self,
+        amount0_out: Balance,
+        amount1_out: Balance,
+        to: AccountId,
+        data: Vec<u8>,
+    ) -> UniswapPairResult<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("IUniswapV2Pair::swap")))
+                    .push_arg(amount0_out)
+                    .push_arg(amount1_out)
+                    .push_arg(to)
+                    .push_arg(data),
+            )
+            .returns::<UniswapPairResult<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::swap failed: {:?}", error))
+    }
+
+    pub fn skim(&self, to: AccountId) -> UniswapPairResult<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!("IUniswapV2Pair::skim")))
+                    .push_arg(to),
+            )
+            .returns::<UniswapPairResult<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::skim failed: {:?}", error))
+    }
+
+    pub fn sync(&self) -> UniswapPairResult<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IUniswapV2Pair::sync"
+            ))))
+            .returns::<UniswapPairResult<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IUniswapV2Pair::sync failed: {:?}", error))
+    }
 }
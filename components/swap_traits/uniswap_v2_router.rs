@@ -0,0 +1,325 @@
+//! Adds and removes liquidity and swaps tokens safely, on behalf of users.
+//!
+//! # References
+//!
+//! - <https://github.com/Uniswap/v2-periphery/blob/master/contracts/interfaces/IUniswapV2Router01.sol>
+//! - <https://github.com/Uniswap/v2-periphery/blob/master/contracts/interfaces/IUniswapV2Router02.sol>
+//! - <https://docs.uniswap.org/protocol/V2/reference/smart-contracts/router-02>
+
+use crate::{Erc20Error, UniswapFactoryError, UniswapPairError};
+use ink_env::AccountId;
+use ink_lang as ink;
+use ink_prelude::vec::Vec;
+use uniswap_v2_library::LibraryError;
+
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+type Timestamp = <ink_env::DefaultEnvironment as ink_env::Environment>::Timestamp;
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UniswapRouterError {
+    /// The deadline has passed.
+    Expired,
+    IdenticalAddresses,
+    ZeroAddress,
+    /// Fewer of token A than the given minimum would be used or paid out.
+    InsufficientAAmount,
+    /// Fewer of token B than the given minimum would be used or paid out.
+    InsufficientBAmount,
+    InsufficientAmount,
+    InsufficientInputAmount,
+    /// A swap would take more than the given maximum, namely the contained amount.
+    ExcessiveInputAmount(Balance),
+    /// A swap would pay out less than the given minimum.
+    InsufficientOutputAmount,
+    InsufficientLiquidity,
+    /// A swap path has fewer than two tokens, or doesn't start or end
+    /// with the wrapped native token as needed.
+    InvalidPath,
+    /// The factory has no pair for the tokens.
+    PairNotFound,
+    /// The factory's fee isn't less than the fee denominator.
+    InvalidFee,
+    Overflow,
+    Factory(UniswapFactoryError),
+    Pair(UniswapPairError),
+    Erc20(Erc20Error),
+}
+
+impl From<LibraryError> for UniswapRouterError {
+    fn from(error: LibraryError) -> Self {
+        match error {
+            LibraryError::IdenticalAddresses => UniswapRouterError::IdenticalAddresses,
+            LibraryError::ZeroAddress => UniswapRouterError::ZeroAddress,
+            LibraryError::InsufficientAmount => UniswapRouterError::InsufficientAmount,
+            LibraryError::InsufficientInputAmount => UniswapRouterError::InsufficientInputAmount,
+            LibraryError::InsufficientOutputAmount => UniswapRouterError::InsufficientOutputAmount,
+            LibraryError::InsufficientLiquidity => UniswapRouterError::InsufficientLiquidity,
+            LibraryError::InvalidPath => UniswapRouterError::InvalidPath,
+            LibraryError::InvalidFee => UniswapRouterError::InvalidFee,
+            LibraryError::Overflow => UniswapRouterError::Overflow,
+        }
+    }
+}
+
+impl From<UniswapFactoryError> for UniswapRouterError {
+    fn from(error: UniswapFactoryError) -> Self {
+        UniswapRouterError::Factory(error)
+    }
+}
+
+impl From<UniswapPairError> for UniswapRouterError {
+    fn from(error: UniswapPairError) -> Self {
+        UniswapRouterError::Pair(error)
+    }
+}
+
+impl From<Erc20Error> for UniswapRouterError {
+    fn from(error: Erc20Error) -> Self {
+        UniswapRouterError::Erc20(error)
+    }
+}
+
+pub type UniswapRouterResult<T> = core::result::Result<T, UniswapRouterError>;
+
+#[ink::trait_definition]
+pub trait IUniswapV2Router {
+    #[ink(message)]
+    fn factory(&self) -> AccountId;
+
+    /// The wrapped native token, which the `*_native` messages
+    /// wrap and unwrap the native currency with.
+    #[ink(message)]
+    fn wnative(&self) -> AccountId;
+
+    /// Deposits up to the desired amounts of two tokens in their pair,
+    /// at the pair's current price, and mints liquidity tokens to `to`.
+    ///
+    /// Creates the pair if it doesn't exist yet, in which case the desired
+    /// amounts set the price. The caller must have approved the router to
+    /// spend both tokens. `deadline` is a block timestamp in milliseconds.
+    ///
+    /// Returns the amounts of token A and B deposited,
+    /// and the liquidity minted.
+    #[ink(message)]
+    #[allow(clippy::too_many_arguments)]
+    fn add_liquidity(
+        &mut self,
+        token_a: AccountId,
+        token_b: AccountId,
+        amount_a_desired: Balance,
+        amount_b_desired: Balance,
+        amount_a_min: Balance,
+        amount_b_min: Balance,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<(Balance, Balance, Balance)>;
+
+    /// Burns `liquidity` of the caller's liquidity tokens for two tokens,
+    /// and pays out at least the minimum amounts of them to `to`.
+    ///
+    /// The caller must have approved the router to spend the liquidity tokens.
+    /// `deadline` is a block timestamp in milliseconds.
+    ///
+    /// Returns the amounts of token A and B paid out.
+    #[ink(message)]
+    #[allow(clippy::too_many_arguments)]
+    fn remove_liquidity(
+        &mut self,
+        token_a: AccountId,
+        token_b: AccountId,
+        liquidity: Balance,
+        amount_a_min: Balance,
+        amount_b_min: Balance,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<(Balance, Balance)>;
+
+    /// Swaps exactly `amount_in` of the first token in `path` for as much as
+    /// possible, and at least `amount_out_min`, of the last one.
+    ///
+    /// Each pair of consecutive tokens in `path` is a hop through their pair,
+    /// which pays its output straight to the pair of the next hop.
+    /// The caller must have approved the router to spend `amount_in`.
+    /// `deadline` is a block timestamp in milliseconds.
+    ///
+    /// Returns the amount of each token along `path` that was swapped.
+    #[ink(message)]
+    fn swap_exact_tokens_for_tokens(
+        &mut self,
+        amount_in: Balance,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Returns the amount of each token along `path` that swapping
+    /// `amount_in` of the first one would yield, at the current reserves.
+    #[ink(message)]
+    fn get_amounts_out(
+        &self,
+        amount_in: Balance,
+        path: Vec<AccountId>,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Swaps as little as possible, and at most `amount_in_max`, of the first
+    /// token in `path` for exactly `amount_out` of the last one.
+    ///
+    /// Hops like `swap_exact_tokens_for_tokens`.
+    /// The caller must have approved the router to spend `amount_in_max`.
+    /// `deadline` is a block timestamp in milliseconds.
+    ///
+    /// Returns the amount of each token along `path` that was swapped.
+    #[ink(message)]
+    fn swap_tokens_for_exact_tokens(
+        &mut self,
+        amount_out: Balance,
+        amount_in_max: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Returns the amount of each token along `path` that swapping for
+    /// `amount_out` of the last one would take, at the current reserves.
+    #[ink(message)]
+    fn get_amounts_in(
+        &self,
+        amount_out: Balance,
+        path: Vec<AccountId>,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Like `add_liquidity`, with the transferred value as the desired amount
+    /// of the wrapped native token. Refunds whatever of it isn't deposited.
+    ///
+    /// Returns the amounts of the token and of the native currency deposited,
+    /// and the liquidity minted.
+    #[ink(message, payable)]
+    fn add_liquidity_native(
+        &mut self,
+        token: AccountId,
+        amount_token_desired: Balance,
+        amount_token_min: Balance,
+        amount_native_min: Balance,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<(Balance, Balance, Balance)>;
+
+    /// Like `remove_liquidity`, paying out the native currency
+    /// instead of the wrapped native token.
+    ///
+    /// Returns the amounts of the token and of the native currency paid out.
+    #[ink(message)]
+    fn remove_liquidity_native(
+        &mut self,
+        token: AccountId,
+        liquidity: Balance,
+        amount_token_min: Balance,
+        amount_native_min: Balance,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<(Balance, Balance)>;
+
+    /// Like `swap_exact_tokens_for_tokens`, swapping exactly the transferred
+    /// value along a `path` starting with the wrapped native token.
+    #[ink(message, payable)]
+    fn swap_exact_native_for_tokens(
+        &mut self,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Like `swap_tokens_for_exact_tokens`, along a `path` ending with the
+    /// wrapped native token, paying out the native currency.
+    #[ink(message)]
+    fn swap_tokens_for_exact_native(
+        &mut self,
+        amount_out: Balance,
+        amount_in_max: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Like `swap_exact_tokens_for_tokens`, along a `path` ending with the
+    /// wrapped native token, paying out the native currency.
+    #[ink(message)]
+    fn swap_exact_tokens_for_native(
+        &mut self,
+        amount_in: Balance,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Like `swap_tokens_for_exact_tokens`, along a `path` starting with the
+    /// wrapped native token, with the transferred value as the maximum input.
+    /// Refunds whatever of it isn't swapped.
+    #[ink(message, payable)]
+    fn swap_native_for_exact_tokens(
+        &mut self,
+        amount_out: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Vec<Balance>>;
+
+    /// Like `remove_liquidity_native`, for tokens that take a fee on transfer.
+    ///
+    /// Pays out the router's whole balance of the token,
+    /// which is what remains after the fee.
+    ///
+    /// Returns the amount of the native currency paid out.
+    #[ink(message)]
+    fn remove_liquidity_native_supporting_fee_on_transfer_tokens(
+        &mut self,
+        token: AccountId,
+        liquidity: Balance,
+        amount_token_min: Balance,
+        amount_native_min: Balance,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<Balance>;
+
+    /// Like `swap_exact_tokens_for_tokens`, for paths with tokens that take a
+    /// fee on transfer.
+    ///
+    /// Each hop swaps what its pair actually received, and `amount_out_min`
+    /// bounds what `to` actually received.
+    #[ink(message)]
+    fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
+        &mut self,
+        amount_in: Balance,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<()>;
+
+    /// Like `swap_exact_native_for_tokens`, for paths with tokens that take a
+    /// fee on transfer.
+    #[ink(message, payable)]
+    fn swap_exact_native_for_tokens_supporting_fee_on_transfer_tokens(
+        &mut self,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<()>;
+
+    /// Like `swap_exact_tokens_for_native`, for paths with tokens that take a
+    /// fee on transfer.
+    #[ink(message)]
+    fn swap_exact_tokens_for_native_supporting_fee_on_transfer_tokens(
+        &mut self,
+        amount_in: Balance,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: Timestamp,
+    ) -> UniswapRouterResult<()>;
+}
@@ -0,0 +1,48 @@
+//! Replacing the code of a deployed contract, keeping its account and storage.
+//!
+//! Upgrading takes two steps: `upgrade` switches to the new code, whose
+//! `migrate` then brings the storage to its layout. Contracts store the
+//! version of their storage layout to tell which migration, if any, to run.
+//!
+//! ink! loads the whole storage struct of a contract before any of its
+//! messages runs, `migrate` included, and fails if a field can't be decoded.
+//! So the new code can only migrate from layouts it can load: ones that differ
+//! from its own by `Mapping` fields, which are read lazily, added at the end.
+//!
+//! # References
+//!
+//! - <https://use.ink/3.x/basics/upgradeable-contracts>
+
+use ink_lang as ink;
+
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum UpgradeError {
+    /// The caller may not upgrade the contract.
+    Forbidden,
+    /// No code has been uploaded with the hash.
+    CodeNotFound,
+    /// The stored layout is one the code can't migrate from.
+    UnknownStorageVersion(u32),
+}
+
+pub type UpgradeResult<T> = core::result::Result<T, UpgradeError>;
+
+#[ink::trait_definition]
+pub trait Upgradeable {
+    /// Returns the version of the stored layout.
+    #[ink(message)]
+    fn storage_version(&self) -> u32;
+
+    /// Replaces the code of the contract with the uploaded code of `code_hash`,
+    /// from the next call on.
+    ///
+    /// `migrate` should be called right after.
+    #[ink(message)]
+    fn upgrade(&mut self, code_hash: [u8; 32]) -> UpgradeResult<()>;
+
+    /// Brings the stored layout up to the version of the code,
+    /// doing nothing if it already is.
+    #[ink(message)]
+    fn migrate(&mut self) -> UpgradeResult<()>;
+}
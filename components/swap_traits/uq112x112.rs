@@ -0,0 +1,116 @@
+//! Binary fixed-point numbers with 112 integer and 112 fractional bits.
+//!
+//! Used for the pair's price accumulators.
+//!
+//! # References
+//!
+//! - <https://github.com/Uniswap/v2-core/blob/master/contracts/libraries/UQ112x112.sol>
+//! - <https://docs.uniswap.org/protocol/V2/concepts/core-concepts/oracles>
+
+use ink_storage::traits::{PackedLayout, SpreadAllocate, SpreadLayout};
+use primitive_types::U256;
+
+/// A UQ112x112 fixed-point number.
+///
+/// Solidity keeps these in a `uint224`, and the cumulative prices summed from
+/// them in a `uint256` that is meant to overflow. This type holds all 256 bits,
+/// as the little-endian limbs of a [`U256`], so that it can serve as both and
+/// still be kept in contract storage and returned from messages.
+#[derive(
+    Debug,
+    Default,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    scale::Encode,
+    scale::Decode,
+    SpreadLayout,
+    PackedLayout,
+    SpreadAllocate,
+)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink_storage::traits::StorageLayout)
+)]
+pub struct UQ112x112([u64; 4]);
+
+impl UQ112x112 {
+    /// Encodes an integer as a fixed-point number.
+    pub fn encode(y: u128) -> Self {
+        Self::from(U256::from(y) << 112)
+    }
+
+    /// Divides by an integer.
+    ///
+    /// Panics if `x` is zero.
+    pub fn uqdiv(self, x: u128) -> Self {
+        Self::from(U256::from(self) / U256::from(x))
+    }
+
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self::from(U256::from(self).overflowing_add(U256::from(other)).0)
+    }
+
+    pub fn wrapping_mul(self, x: u32) -> Self {
+        Self::from(U256::from(self).overflowing_mul(U256::from(x)).0)
+    }
+}
+
+impl From<U256> for UQ112x112 {
+    fn from(value: U256) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<UQ112x112> for U256 {
+    fn from(value: UQ112x112) -> Self {
+        U256(value.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uq(value: U256) -> UQ112x112 {
+        UQ112x112::from(value)
+    }
+
+    #[test]
+    fn encode_shifts_into_the_integer_bits() {
+        assert_eq!(UQ112x112::encode(1), uq(U256::one() << 112));
+        assert_eq!(UQ112x112::encode(3), uq(U256::from(3) << 112));
+    }
+
+    #[test]
+    fn uqdiv_rounds_down() {
+        assert_eq!(uq(U256::from(7)).uqdiv(2), uq(U256::from(3)));
+        // A third of one, whose fractional bits don't divide evenly.
+        let third = U256::from(UQ112x112::encode(1).uqdiv(3));
+        assert!(third * 3 < U256::one() << 112);
+        assert!((third + 1) * 3 > U256::one() << 112);
+    }
+
+    #[test]
+    fn wrapping_add_wraps_at_256_bits() {
+        assert_eq!(
+            uq(U256::MAX).wrapping_add(uq(U256::from(2))),
+            uq(U256::one())
+        );
+    }
+
+    #[test]
+    fn wrapping_mul_wraps_at_256_bits() {
+        assert_eq!(uq(U256::one() << 255).wrapping_mul(2), uq(U256::zero()));
+        assert_eq!(uq(U256::MAX).wrapping_mul(3), uq(U256::MAX - 2));
+    }
+
+    #[test]
+    fn converts_to_and_from_u256() {
+        let value = U256([1, 2, 3, 4]);
+        assert_eq!(U256::from(UQ112x112::from(value)), value);
+        let fixed = UQ112x112([5, 6, 7, 8]);
+        assert_eq!(UQ112x112::from(U256::from(fixed)), fixed);
+    }
+}
@@ -0,0 +1,63 @@
+//! An `Erc20` token backed 1:1 by the chain's native currency,
+//! so that pairs can hold it like any other token.
+//!
+//! # References
+//!
+//! - <https://github.com/gnosis/canonical-weth/blob/master/contracts/WETH9.sol>
+//! - <https://github.com/Uniswap/v2-periphery/blob/master/contracts/interfaces/IWETH.sol>
+
+use crate::Erc20Result;
+use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+use ink_env::{AccountId, DefaultEnvironment};
+use ink_lang as ink;
+
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+
+#[ink::trait_definition]
+pub trait IWrappedNative {
+    /// Mints the transferred value to the caller.
+    #[ink(message, payable)]
+    fn deposit(&mut self);
+
+    /// Burns `value` of the caller's tokens and pays it out in the native currency.
+    #[ink(message)]
+    fn withdraw(&mut self, value: Balance) -> Erc20Result<()>;
+}
+
+/// Calls the `IWrappedNative` messages of a deployed token.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IWrappedNativeRef {
+    account_id: AccountId,
+}
+
+impl IWrappedNativeRef {
+    pub fn new(account_id: AccountId) -> Self {
+        Self { account_id }
+    }
+
+    pub fn deposit(&self, value: Balance) {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .transferred_value(value)
+            .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                "IWrappedNative::deposit"
+            ))))
+            .returns::<()>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IWrappedNative::deposit failed: {:?}", error))
+    }
+
+    pub fn withdraw(&self, value: Balance) -> Erc20Result<()> {
+        build_call::<DefaultEnvironment>()
+            .call_type(Call::new().callee(self.account_id))
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "IWrappedNative::withdraw"
+                )))
+                .push_arg(value),
+            )
+            .returns::<Erc20Result<()>>()
+            .fire()
+            .unwrap_or_else(|error| panic!("IWrappedNative::withdraw failed: {:?}", error))
+    }
+}
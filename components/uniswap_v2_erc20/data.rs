@@ -0,0 +1,292 @@
+//! Token balances and allowances.
+//!
+//! Kept outside of the contract so that other contracts that are also tokens,
+//! like the pair with its liquidity tokens, can embed the same logic in their
+//! storage. Emitting `Transfer` and `Approval` events is left to the embedding
+//! contract, since ink! events belong to a single contract.
+//!
+//! # References
+//!
+//! - <https://eips.ethereum.org/EIPS/eip-2612>
+//! - <https://eips.ethereum.org/EIPS/eip-712>
+
+use ink_env::hash::{Blake2x256, HashOutput, Keccak256};
+use ink_env::{AccountId, DefaultEnvironment, Hash};
+use ink_storage::{
+    traits::{SpreadAllocate, SpreadLayout},
+    Mapping,
+};
+use swap_traits::{Erc20Error, Erc20Result};
+
+type Balance = <ink_env::DefaultEnvironment as ink_env::Environment>::Balance;
+type Timestamp = <ink_env::DefaultEnvironment as ink_env::Environment>::Timestamp;
+
+/// The type of the domain separator, named after EIP-712's.
+///
+/// ink! has no access to a chain id, so chains are told apart by an identifier
+/// passed to the constructor, such as the genesis hash, which plays the part of
+/// Ethereum's chain id.
+const DOMAIN_TYPE: &[u8] =
+    b"EIP712Domain(string name,string version,Hash chainId,AccountId verifyingContract)";
+const DOMAIN_VERSION: &[u8] = b"1";
+/// The type of a permit, named after EIP-2612's.
+const PERMIT_TYPE: &[u8] =
+    b"Permit(AccountId owner,AccountId spender,Balance value,u64 nonce,u64 deadline)";
+
+#[derive(SpreadAllocate, SpreadLayout)]
+#[cfg_attr(feature = "std", derive(ink_storage::traits::StorageLayout))]
+pub struct Erc20Data {
+    pub total_supply: Balance,
+    pub balance_of: Mapping<AccountId, Balance>,
+    pub allowance: Mapping<(AccountId, AccountId), Balance>,
+    /// The next permit nonce for each owner.
+    pub nonces: Mapping<AccountId, u64>,
+    pub domain_separator: [u8; 32],
+    /// The chain the domain separator binds permits to.
+    pub chain_id: Hash,
+}
+
+fn keccak256(input: &[u8]) -> [u8; 32] {
+    let mut output = <Keccak256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Keccak256>(input, &mut output);
+    output
+}
+
+fn keccak256_encoded<T: scale::Encode>(input: &T) -> [u8; 32] {
+    let mut output = <Keccak256 as HashOutput>::Type::default();
+    ink_env::hash_encoded::<Keccak256, _>(input, &mut output);
+    output
+}
+
+impl Erc20Data {
+    /// Binds permits to this token, under `name`, on the chain `chain_id`,
+    /// at the address `this`.
+    ///
+    /// Must be called from the constructor.
+    pub fn init_domain_separator(&mut self, name: &str, chain_id: Hash, this: &AccountId) {
+        self.chain_id = chain_id;
+        self.domain_separator = keccak256_encoded(&(
+            keccak256(DOMAIN_TYPE),
+            keccak256(name.as_bytes()),
+            keccak256(DOMAIN_VERSION),
+            chain_id,
+            this,
+        ));
+    }
+
+    pub fn permit_typehash(&self) -> [u8; 32] {
+        keccak256(PERMIT_TYPE)
+    }
+
+    pub fn nonces(&self, owner: &AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    /// Returns the digest the owner signs to permit `spender` to spend
+    /// `value` of their tokens until `deadline`, as their permit `nonce`.
+    ///
+    /// The digest follows the structure of EIP-712 but not its encoding,
+    /// as it hashes SCALE encoded values rather than ABI encoded ones, so
+    /// wallets can't produce it with `eth_signTypedData`. It is
+    ///
+    /// ```text
+    /// keccak256(0x19 0x01 ++ domain_separator ++ keccak256(
+    ///     permit_typehash ++ owner ++ spender ++ value ++ nonce ++ deadline
+    /// ))
+    /// ```
+    ///
+    /// where `++` concatenates, the accounts and hashes are 32 bytes each,
+    /// `value` is a 16-byte and `nonce` and `deadline` are 8-byte little-endian
+    /// integers. `domain_separator` is likewise
+    ///
+    /// ```text
+    /// keccak256(
+    ///     keccak256(DOMAIN_TYPE) ++ keccak256(name) ++ keccak256("1") ++ chain_id ++ token
+    /// )
+    /// ```
+    ///
+    /// The digest itself is signed, without the prefix of Ethereum's `eth_sign`.
+    pub fn permit_digest(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: Balance,
+        nonce: u64,
+        deadline: Timestamp,
+    ) -> [u8; 32] {
+        let permit_hash = keccak256_encoded(&(
+            self.permit_typehash(),
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        ));
+        keccak256_encoded(&(*b"\x19\x01", self.domain_separator, permit_hash))
+    }
+
+    /// Approves `spender` to spend `value` of `owner`'s tokens,
+    /// authorized by `owner`'s ECDSA signature rather than by the caller.
+    ///
+    /// The signature is over the `permit_digest` of the permit, with the
+    /// recovery id `v` either as 0 and 1 or as Ethereum's 27 and 28. The owner
+    /// must be the account of the signing key, i.e. the BLAKE2 hash of the
+    /// compressed public key, as for Substrate's ECDSA accounts. ink! offers no
+    /// sr25519 signature verification, so sr25519 accounts can't sign permits.
+    #[allow(clippy::too_many_arguments)]
+    pub fn permit(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        v: u8,
+        r: [u8; 32],
+        s: [u8; 32],
+    ) -> Erc20Result<()> {
+        if deadline < ink_env::block_timestamp::<DefaultEnvironment>() {
+            return Err(Erc20Error::Expired);
+        }
+
+        let nonce = self.nonces(owner);
+        let digest = self.permit_digest(owner, spender, value, nonce, deadline);
+
+        let mut signature = [0; 65];
+        signature[..32].copy_from_slice(&r);
+        signature[32..64].copy_from_slice(&s);
+        signature[64] = if v >= 27 { v - 27 } else { v };
+        let mut public_key = [0; 33];
+        ink_env::ecdsa_recover(&signature, &digest, &mut public_key)
+            .map_err(|_| Erc20Error::InvalidSignature)?;
+        let mut signer = <Blake2x256 as HashOutput>::Type::default();
+        ink_env::hash_bytes::<Blake2x256>(&public_key, &mut signer);
+        if AccountId::from(signer) != *owner {
+            return Err(Erc20Error::InvalidSignature);
+        }
+
+        self.nonces.insert(owner, &(nonce + 1));
+        self.approve(owner, spender, value);
+        Ok(())
+    }
+
+    pub fn balance_of(&self, owner: &AccountId) -> Balance {
+        self.balance_of.get(owner).unwrap_or_default()
+    }
+
+    pub fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance {
+        self.allowance.get((owner, spender)).unwrap_or_default()
+    }
+
+    pub fn mint(&mut self, to: &AccountId, value: Balance) -> Erc20Result<()> {
+        let total_supply = self
+            .total_supply
+            .checked_add(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+        let to_balance = self
+            .balance_of(to)
+            .checked_add(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+
+        self.total_supply = total_supply;
+        self.balance_of.insert(to, &to_balance);
+        Ok(())
+    }
+
+    pub fn burn(&mut self, from: &AccountId, value: Balance) -> Erc20Result<()> {
+        let total_supply = self
+            .total_supply
+            .checked_sub(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+        let from_balance = self
+            .balance_of(from)
+            .checked_sub(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+
+        self.total_supply = total_supply;
+        self.balance_of.insert(from, &from_balance);
+        Ok(())
+    }
+
+    pub fn transfer_from_to(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        value: Balance,
+    ) -> Erc20Result<()> {
+        let from_balance = self.balance_of(from);
+        if from_balance < value {
+            return Err(Erc20Error::InsufficientBalance);
+        }
+
+        if from == to {
+            return Ok(());
+        }
+
+        let to_balance = self
+            .balance_of(to)
+            .checked_add(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+        self.balance_of.insert(from, &(from_balance - value));
+        self.balance_of.insert(to, &to_balance);
+        Ok(())
+    }
+
+    pub fn approve(&mut self, owner: &AccountId, spender: &AccountId, value: Balance) {
+        self.allowance.insert((owner, spender), &value);
+    }
+
+    /// Adds `value` to the allowance `spender` has over `owner`'s tokens,
+    /// returning the new allowance.
+    pub fn increase_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: Balance,
+    ) -> Erc20Result<Balance> {
+        let allowance = self
+            .allowance(owner, spender)
+            .checked_add(value)
+            .ok_or(Erc20Error::BalanceOverflowOrUnderflow)?;
+        self.approve(owner, spender, allowance);
+        Ok(allowance)
+    }
+
+    /// Subtracts `value` from the allowance `spender` has over `owner`'s tokens,
+    /// returning the new allowance.
+    ///
+    /// Unlike spending, this also lowers an infinite allowance.
+    pub fn decrease_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: Balance,
+    ) -> Erc20Result<Balance> {
+        let allowance = self
+            .allowance(owner, spender)
+            .checked_sub(value)
+            .ok_or(Erc20Error::InsufficientAllowance)?;
+        self.approve(owner, spender, allowance);
+        Ok(allowance)
+    }
+
+    /// Deducts `value` from the allowance `spender` has over `owner`'s tokens.
+    ///
+    /// An allowance of `Balance::MAX` is infinite and never deducted from.
+    /// Returns the new allowance if it changed.
+    pub fn spend_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: Balance,
+    ) -> Erc20Result<Option<Balance>> {
+        let allowance = self.allowance(owner, spender);
+        if allowance < value {
+            return Err(Erc20Error::InsufficientAllowance);
+        }
+        if allowance == Balance::MAX || value == 0 {
+            return Ok(None);
+        }
+        self.approve(owner, spender, allowance - value);
+        Ok(Some(allowance - value))
+    }
+}
@@ -2,12 +2,18 @@
 
 use ink_lang as ink;
 
+mod data;
+
+pub use data::*;
+
 #[ink::contract]
 mod uniswap_v2_erc20 {
     use ink_prelude::string::{String, ToString};
-    use ink_storage::{traits::SpreadAllocate, Mapping};
-    use swap_traits::{Erc20, Erc20Error, Erc20Result};
+    use crate::Erc20Data;
+    use ink_storage::traits::SpreadAllocate;
+    use swap_traits::{Erc20, Erc20Error, Erc20Metadata, Erc20Result};
 
+    /// The metadata of tokens created with the `default` constructor.
     const NAME: &'static str = "Uniswap V2";
     const SYMBOL: &'static str = "UNI-V2";
     const DECIMALS: u8 = 18;
@@ -15,9 +21,10 @@ mod uniswap_v2_erc20 {
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct UniswapV2Erc20 {
-        pub total_supply: Balance,
-        pub balance_of: Mapping<AccountId, Balance>,
-        pub allowance: Mapping<(AccountId, AccountId), Balance>,
+        erc20: Erc20Data,
+        name: String,
+        symbol: String,
+        decimals: u8,
     }
 
     #[ink(event)]
@@ -39,103 +46,100 @@ mod uniswap_v2_erc20 {
     }
 
     impl UniswapV2Erc20 {
+        /// Creates a token and mints `initial_supply` of it to the caller.
+        ///
+        /// `chain_id` tells the chain apart from others in permits,
+        /// e.g. its genesis hash.
         #[ink(constructor)]
-        pub fn new(initial_supply: Balance) -> Self {
+        pub fn new(
+            initial_supply: Balance,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            chain_id: Hash,
+        ) -> Self {
             ink_lang::utils::initialize_contract(|contract| {
-                Self::new_init(contract, initial_supply)
+                Self::new_init(contract, initial_supply, name, symbol, decimals, chain_id)
             })
         }
 
         #[ink(constructor)]
-        pub fn default() -> Self {
-            ink_lang::utils::initialize_contract(|_| {})
+        pub fn default(chain_id: Hash) -> Self {
+            ink_lang::utils::initialize_contract(|contract| {
+                Self::new_init(contract, 0, NAME.to_string(), SYMBOL.to_string(), DECIMALS, chain_id)
+            })
         }
 
+        /// The hash binding permits to this contract.
         #[ink(message)]
-        pub fn name(&self) -> String {
-            NAME.to_string()
+        pub fn domain_separator(&self) -> [u8; 32] {
+            self.erc20.domain_separator
         }
 
+        /// The chain permits are bound to.
         #[ink(message)]
-        pub fn symbol(&self) -> String {
-            SYMBOL.to_string()
+        pub fn chain_id(&self) -> Hash {
+            self.erc20.chain_id
         }
 
         #[ink(message)]
-        pub fn decimal(&self) -> u8 {
-            DECIMALS
+        pub fn permit_typehash(&self) -> [u8; 32] {
+            self.erc20.permit_typehash()
         }
 
-        fn new_init(&mut self, initial_supply: Balance) {
-            let caller = Self::env().caller();
-            self.balance_of.insert(&caller, &initial_supply);
-            self.total_supply = initial_supply;
-            Self::env().emit_event(Transfer {
-                from: None,
-                to: Some(caller),
-                value: initial_supply,
-            });
+        /// The nonce `owner`'s next permit must be signed with.
+        #[ink(message)]
+        pub fn nonces(&self, owner: AccountId) -> u64 {
+            self.erc20.nonces(&owner)
         }
 
-        fn mint(&mut self, to: &AccountId, value: Balance) -> Erc20Result<()> {
-            let temp_total_supply;
-            if let Some(total_supply) = self.total_supply.checked_add(value) {
-                temp_total_supply = total_supply;
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
-            let to_balance = self.balance_of_impl(to);
-            let temp_to_balance;
-            if let Some(to_balance) = to_balance.checked_add(value) {
-                temp_to_balance = to_balance;
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
-            self.total_supply = temp_total_supply;
-            self.balance_of.insert(to, &(temp_to_balance));
-            self.env().emit_event(Transfer {
-                from: None,
-                to: Some(*to),
+        /// Approves `spender` to spend `value` of `owner`'s tokens,
+        /// authorized by `owner`'s ECDSA signature `(v, r, s)` instead of a call,
+        /// so that someone else can pay for the approval.
+        ///
+        /// `deadline` is a block timestamp in milliseconds.
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+            deadline: Timestamp,
+            v: u8,
+            r: [u8; 32],
+            s: [u8; 32],
+        ) -> Erc20Result<()> {
+            self.erc20.permit(&owner, &spender, value, deadline, v, r, s)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
                 value,
             });
-
             Ok(())
         }
 
-        fn burn(&mut self, from: &AccountId, value: Balance) -> Erc20Result<()> {
-            let temp_total_supply;
-            if let Some(total_supply) = self.total_supply.checked_sub(value) {
-                temp_total_supply = total_supply;
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
-            let from_balance = self.balance_of_impl(from);
-            let temp_from_balance;
-            if let Some(from_balance) = from_balance.checked_sub(value) {
-                temp_from_balance = from_balance;
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
-            self.total_supply = temp_total_supply;
-            self.balance_of.insert(from, &(temp_from_balance));
-            self.env().emit_event(Transfer {
-                from: Some(*from),
-                to: None,
-                value,
+        fn new_init(
+            &mut self,
+            initial_supply: Balance,
+            name: String,
+            symbol: String,
+            decimals: u8,
+            chain_id: Hash,
+        ) {
+            self.erc20
+                .init_domain_separator(&name, chain_id, &Self::env().account_id());
+            self.name = name;
+            self.symbol = symbol;
+            self.decimals = decimals;
+            let caller = Self::env().caller();
+            self.erc20.balance_of.insert(&caller, &initial_supply);
+            self.erc20.total_supply = initial_supply;
+            Self::env().emit_event(Transfer {
+                from: None,
+                to: Some(caller),
+                value: initial_supply,
             });
-            Ok(())
-        }
-
-        fn balance_of_impl(&self, owner: &AccountId) -> Balance {
-            self.balance_of.get(owner).unwrap_or_default()
-        }
-
-        fn allowance_impl(&self, owner: &AccountId, spender: &AccountId) -> Balance {
-            self.allowance.get((owner, spender)).unwrap_or_default()
         }
 
         fn transfer_from_to(
@@ -144,26 +148,7 @@ mod uniswap_v2_erc20 {
             to: &AccountId,
             value: Balance,
         ) -> Erc20Result<()> {
-            let from_balance = self.balance_of_impl(from);
-            if from_balance < value {
-                return Err(Erc20Error::InsufficientBalance);
-            }
-
-            let from_balance = self.balance_of_impl(from);
-
-            if let Some(from_balance) = from_balance.checked_sub(value) {
-                let to_balance = self.balance_of_impl(to);
-
-                if let Some(to_balance) = to_balance.checked_add(value) {
-                    self.balance_of.insert(to, &(to_balance));
-                    self.balance_of.insert(from, &(from_balance));
-                } else {
-                    return Err(Erc20Error::BalanceOverflowOrUnderflow);
-                }
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
+            self.erc20.transfer_from_to(from, to, value)?;
             self.env().emit_event(Transfer {
                 from: Some(*from),
                 to: Some(*to),
@@ -173,15 +158,32 @@ mod uniswap_v2_erc20 {
         }
     }
 
+    impl Erc20Metadata for UniswapV2Erc20 {
+        #[ink(message)]
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        fn symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        fn decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
     impl Erc20 for UniswapV2Erc20 {
         #[ink(message)]
         fn total_supply(&self) -> Balance {
-            self.total_supply
+            self.erc20.total_supply
         }
 
         #[ink(message)]
         fn balance_of(&self, owner: AccountId) -> Balance {
-            self.balance_of_impl(&owner)
+            self.erc20.balance_of(&owner)
         }
 
         #[ink(message)]
@@ -198,28 +200,25 @@ mod uniswap_v2_erc20 {
             value: Balance,
         ) -> Erc20Result<()> {
             let caller = self.env().caller();
-            let allowance = self.allowance_impl(&from, &caller);
-            if allowance < value {
+            if self.erc20.allowance(&from, &caller) < value {
                 return Err(Erc20Error::InsufficientAllowance);
             }
 
-            let temp_allowance;
-            if let Some(allowance) = allowance.checked_sub(value) {
-                temp_allowance = allowance;
-            } else {
-                return Err(Erc20Error::BalanceOverflowOrUnderflow);
-            }
-
             self.transfer_from_to(&from, &to, value)?;
-            self.allowance.insert((&from, &caller), &(temp_allowance));
-
+            if let Some(allowance) = self.erc20.spend_allowance(&from, &caller, value)? {
+                self.env().emit_event(Approval {
+                    owner: from,
+                    spender: caller,
+                    value: allowance,
+                });
+            }
             Ok(())
         }
 
         #[ink(message)]
         fn approve(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
             let owner = self.env().caller();
-            self.allowance.insert((&owner, &spender), &value);
+            self.erc20.approve(&owner, &spender, value);
             self.env().emit_event(Approval {
                 owner,
                 spender,
@@ -230,15 +229,295 @@ mod uniswap_v2_erc20 {
 
         #[ink(message)]
         fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
-            self.allowance_impl(&owner, &spender)
+            self.erc20.allowance(&owner, &spender)
+        }
+
+        #[ink(message)]
+        fn increase_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.erc20.increase_allowance(&owner, &spender, value)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: allowance,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.erc20.decrease_allowance(&owner, &spender, value)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: allowance,
+            });
+            Ok(())
         }
     }
 
-    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
-    /// module and test functions are marked with a `#[test]` attribute.
-    /// The below code is technically just normal Rust code.
     #[cfg(test)]
     mod tests {
-        todo!();
+        use super::*;
+        use ink_env::hash::{Blake2x256, HashOutput};
+        use ink_lang as ink;
+        use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+        type Event = <UniswapV2Erc20 as ::ink_lang::reflect::ContractEventBase>::Type;
+
+        fn accounts() -> ink_env::test::DefaultAccounts<ink_env::DefaultEnvironment> {
+            ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+        }
+
+        fn token() -> UniswapV2Erc20 {
+            UniswapV2Erc20::default(Hash::from([1; 32]))
+        }
+
+        fn key(byte: u8) -> SecretKey {
+            SecretKey::from_slice(&[byte; 32]).unwrap()
+        }
+
+        /// The account of `key`, as `permit` recovers it.
+        fn account_of(key: &SecretKey) -> AccountId {
+            let public_key = PublicKey::from_secret_key(&Secp256k1::new(), key);
+            let mut account = <Blake2x256 as HashOutput>::Type::default();
+            ink_env::hash_bytes::<Blake2x256>(&public_key.serialize(), &mut account);
+            AccountId::from(account)
+        }
+
+        /// Signs a permit of `owner`'s tokens with `key`, returning `(v, r, s)`
+        /// with `v` as 0 or 1.
+        fn sign(
+            token: &UniswapV2Erc20,
+            key: &SecretKey,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+            deadline: Timestamp,
+        ) -> (u8, [u8; 32], [u8; 32]) {
+            let nonce = token.nonces(owner);
+            let digest = token
+                .erc20
+                .permit_digest(&owner, &spender, value, nonce, deadline);
+            let (recovery_id, signature) = Secp256k1::new()
+                .sign_ecdsa_recoverable(&Message::from_slice(&digest).unwrap(), key)
+                .serialize_compact();
+            let mut r = [0; 32];
+            let mut s = [0; 32];
+            r.copy_from_slice(&signature[..32]);
+            s.copy_from_slice(&signature[32..]);
+            (recovery_id.to_i32() as u8, r, s)
+        }
+
+        fn last_approval() -> Approval {
+            let events = ink_env::test::recorded_events().collect::<Vec<_>>();
+            let event = events.last().expect("no event was emitted");
+            match <Event as scale::Decode>::decode(&mut &event.data[..]) {
+                Ok(Event::Approval(approval)) => approval,
+                _ => panic!("the last event isn't an Approval"),
+            }
+        }
+
+        #[ink::test]
+        fn approve_emits_approval() {
+            let mut token = token();
+            let accounts = accounts();
+            assert_eq!(token.approve(accounts.bob, 100), Ok(()));
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 100);
+            let approval = last_approval();
+            assert_eq!(
+                (approval.owner, approval.spender, approval.value),
+                (accounts.alice, accounts.bob, 100)
+            );
+        }
+
+        #[ink::test]
+        fn increase_allowance_adds_to_allowance() {
+            let mut token = token();
+            let accounts = accounts();
+            token.approve(accounts.bob, 100).unwrap();
+            assert_eq!(token.increase_allowance(accounts.bob, 50), Ok(()));
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 150);
+            assert_eq!(last_approval().value, 150);
+            assert_eq!(
+                token.increase_allowance(accounts.bob, Balance::MAX),
+                Err(Erc20Error::BalanceOverflowOrUnderflow)
+            );
+        }
+
+        #[ink::test]
+        fn decrease_allowance_subtracts_from_allowance() {
+            let mut token = token();
+            let accounts = accounts();
+            token.approve(accounts.bob, 100).unwrap();
+            assert_eq!(token.decrease_allowance(accounts.bob, 30), Ok(()));
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 70);
+            assert_eq!(last_approval().value, 70);
+        }
+
+        #[ink::test]
+        fn decrease_allowance_below_zero_fails() {
+            let mut token = token();
+            let accounts = accounts();
+            token.approve(accounts.bob, 100).unwrap();
+            assert_eq!(
+                token.decrease_allowance(accounts.bob, 101),
+                Err(Erc20Error::InsufficientAllowance)
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 100);
+        }
+
+        #[ink::test]
+        fn transfer_from_spends_allowance() {
+            let mut token = UniswapV2Erc20::new(
+                1000,
+                NAME.to_string(),
+                SYMBOL.to_string(),
+                DECIMALS,
+                Hash::default(),
+            );
+            let accounts = accounts();
+            token.approve(accounts.bob, 100).unwrap();
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.charlie, 40),
+                Ok(())
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 60);
+            let approval = last_approval();
+            assert_eq!(
+                (approval.owner, approval.spender, approval.value),
+                (accounts.alice, accounts.bob, 60)
+            );
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.charlie, 61),
+                Err(Erc20Error::InsufficientAllowance)
+            );
+        }
+
+        #[ink::test]
+        fn transfer_from_keeps_infinite_allowance() {
+            let mut token = UniswapV2Erc20::new(
+                1000,
+                NAME.to_string(),
+                SYMBOL.to_string(),
+                DECIMALS,
+                Hash::default(),
+            );
+            let accounts = accounts();
+            token.approve(accounts.bob, Balance::MAX).unwrap();
+            let events = ink_env::test::recorded_events().count();
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.charlie, 400),
+                Ok(())
+            );
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), Balance::MAX);
+            assert_eq!(token.balance_of(accounts.charlie), 400);
+            // Only the `Transfer`, with no `Approval` for an unchanged allowance.
+            assert_eq!(ink_env::test::recorded_events().count(), events + 1);
+        }
+
+        #[ink::test]
+        fn permit_approves_spender() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            let (v, r, s) = sign(&token, &key(1), owner, spender, 100, Timestamp::MAX);
+
+            assert_eq!(
+                token.permit(owner, spender, 100, Timestamp::MAX, v, r, s),
+                Ok(())
+            );
+            assert_eq!(token.allowance(owner, spender), 100);
+            assert_eq!(token.nonces(owner), 1);
+            let approval = last_approval();
+            assert_eq!(
+                (approval.owner, approval.spender, approval.value),
+                (owner, spender, 100)
+            );
+        }
+
+        #[ink::test]
+        fn permit_accepts_ethereum_recovery_ids() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            let (v, r, s) = sign(&token, &key(1), owner, spender, 100, Timestamp::MAX);
+
+            assert_eq!(
+                token.permit(owner, spender, 100, Timestamp::MAX, v + 27, r, s),
+                Ok(())
+            );
+            assert_eq!(token.allowance(owner, spender), 100);
+        }
+
+        #[ink::test]
+        fn permit_increments_nonce() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            for (nonce, value) in [100, 200, 300].into_iter().enumerate() {
+                assert_eq!(token.nonces(owner), nonce as u64);
+                let (v, r, s) = sign(&token, &key(1), owner, spender, value, Timestamp::MAX);
+                assert_eq!(
+                    token.permit(owner, spender, value, Timestamp::MAX, v, r, s),
+                    Ok(())
+                );
+                assert_eq!(token.allowance(owner, spender), value);
+            }
+            assert_eq!(token.nonces(owner), 3);
+        }
+
+        #[ink::test]
+        fn replayed_permit_is_rejected() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            let (v, r, s) = sign(&token, &key(1), owner, spender, 100, Timestamp::MAX);
+            assert_eq!(
+                token.permit(owner, spender, 100, Timestamp::MAX, v, r, s),
+                Ok(())
+            );
+
+            assert_eq!(
+                token.permit(owner, spender, 100, Timestamp::MAX, v, r, s),
+                Err(Erc20Error::InvalidSignature)
+            );
+            assert_eq!(token.nonces(owner), 1);
+        }
+
+        #[ink::test]
+        fn permit_signed_by_another_key_is_rejected() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            let (v, r, s) = sign(&token, &key(2), owner, spender, 100, Timestamp::MAX);
+
+            assert_eq!(
+                token.permit(owner, spender, 100, Timestamp::MAX, v, r, s),
+                Err(Erc20Error::InvalidSignature)
+            );
+            assert_eq!(token.allowance(owner, spender), 0);
+            assert_eq!(token.nonces(owner), 0);
+        }
+
+        #[ink::test]
+        fn expired_permit_is_rejected() {
+            let mut token = token();
+            let owner = account_of(&key(1));
+            let spender = accounts().bob;
+            let (v, r, s) = sign(&token, &key(1), owner, spender, 100, 0);
+            ink_env::test::advance_block::<ink_env::DefaultEnvironment>();
+
+            assert_eq!(
+                token.permit(owner, spender, 100, 0, v, r, s),
+                Err(Erc20Error::Expired)
+            );
+            assert_eq!(token.nonces(owner), 0);
+        }
     }
 }
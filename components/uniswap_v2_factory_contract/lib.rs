@@ -4,50 +4,49 @@ use ink_lang as ink;
 
 #[ink::contract]
 mod uniswap_v2_factory{
-    use ink_lang as ink;
+    use ink_lang::ToAccountId;
     use ink_storage::{traits::SpreadAllocate, Mapping};
-    // pub use swap_traits::{IUniswapV2Factory, UniswapFactoryError, UniswapFactoryResult};
+    use swap_traits::{
+        IUniswapV2Factory, IUniswapV2Pair, Ownable2Step, Ownable2StepData, OwnableResult,
+        UniswapFactoryError, UniswapFactoryResult, UpgradeError, UpgradeResult, Upgradeable,
+    };
+    use uniswap_v2_library::{pair_salt, sort_tokens, LibraryError, FEE_DENOMINATOR};
+    use uniswap_v2_pair::UniswapV2PairRef;
 
+    /// The version of the storage layout below. Bump it, and teach
+    /// `migrate` the previous one, whenever the layout changes.
+    ///
+    /// Only layouts that add `Mapping` fields after the existing ones can be
+    /// migrated to, as the other fields are loaded before `migrate` runs.
+    const STORAGE_VERSION: u32 = 1;
 
-    #[ink::trait_definition]
-    pub trait IUniswapV2Factory {
-        #[ink(message)]
-        fn fee_to(&self) -> AccountId;
-
-        #[ink(message)]
-        fn fee_to_setter(&self) -> AccountId;
-
-        #[ink(message)]
-        fn get_pair(&self, toekn_a: AccountId, token_b: AccountId) -> AccountId;
-
-        #[ink(message)]
-        fn all_pairs(&self, log_value: u64) -> AccountId;
-
-        // #[ink(message)]
-        // fn all_pairs_length(&mut self) -> u64;
-
-        // #[ink(message)]
-        // fn create_pair(&mut self, token_a: AccountId, token_b: AccountId) -> AccountId;
-
-        #[ink(message)]
-        fn set_fee_to(&mut self, address: AccountId);
-
-        #[ink(message)]
-        fn set_fee_to_setter(&mut self, address: AccountId);
-    }
-
-
-
-    #[derive(Default)]
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct UniswapV2Factory {
+        /// The version of the layout the storage is in.
+        ///
+        /// Must stay the first field, so that its key is the same in every
+        /// layout.
+        storage_version: u32,
         fee_to: AccountId,
-        fee_to_setter: AccountId,
-        // get_pair: Mapping<AccountId, Mapping<AccountId, AccountId>>,
-        // all_pairs: AccountId,
-        fees: Balance
-
+        /// The owner, who may set `fee_to` and the fees.
+        fee_to_setter: Ownable2StepData,
+        /// Pairs by their tokens, in both orders.
+        get_pair: Mapping<(AccountId, AccountId), AccountId>,
+        /// Pairs in the order they were created.
+        all_pairs: Mapping<u64, AccountId>,
+        all_pairs_length: u64,
+        /// The swap fee, in thousandths.
+        fees: Balance,
+        /// The code pairs are instantiated from.
+        pair_code_hash: Hash,
+        /// May pause and unpause pairs.
+        guardian: AccountId,
+        /// Pairs paused on their own.
+        paused: Mapping<AccountId, bool>,
+        all_paused: bool,
+        /// The chain pairs bind their liquidity token permits to.
+        chain_id: Hash,
     }
 
 
@@ -57,94 +56,481 @@ mod uniswap_v2_factory{
         token0: AccountId,
         #[ink(topic)]
         token1: AccountId,
+        pair: AccountId,
+        /// `all_pairs_length` after this pair, i.e. 1 for the first pair created.
+        all_pairs_length: u64,
+    }
+
+    #[ink(event)]
+    pub struct FeesChanged {
+        old_fees: Balance,
+        new_fees: Balance,
+    }
+
+    #[ink(event)]
+    pub struct FeeToChanged {
+        #[ink(topic)]
+        old_fee_to: AccountId,
+        #[ink(topic)]
+        new_fee_to: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianChanged {
+        #[ink(topic)]
+        old_guardian: AccountId,
+        #[ink(topic)]
+        new_guardian: AccountId,
+    }
+
+    /// Emitted when a pair is paused or unpaused, or all pairs with no `pair`.
+    #[ink(event)]
+    pub struct PauseChanged {
+        #[ink(topic)]
+        pair: Option<AccountId>,
+        paused: bool,
+    }
+
+    #[ink(event)]
+    pub struct Upgraded {
+        code_hash: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        pending_owner: AccountId,
+    }
+
+    /// Emitted on acceptance and renouncement, the latter with no new owner.
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
         #[ink(topic)]
-        pair: Balance
+        new_owner: Option<AccountId>,
     }
 
     impl UniswapV2Factory {
         
 
+        /// `chain_id` tells the chain apart from others in the permits of
+        /// the pairs' liquidity tokens, e.g. its genesis hash.
         #[ink(constructor)]
-        pub fn new(_fees: Balance) -> Self {
-            // Sets fees to zero if not in valid range
-            Self {
-                fees: if _fees >= 1000 { 0 } else { _fees },
-                ..Default::default()
-            }
+        pub fn new(_fees: Balance, pair_code_hash: Hash, chain_id: Hash) -> Self {
+            ink_lang::utils::initialize_contract(|contract: &mut Self| {
+                // Sets fees to zero if not in valid range
+                contract.fees = if _fees >= FEE_DENOMINATOR { 0 } else { _fees };
+                contract.pair_code_hash = pair_code_hash;
+                contract.chain_id = chain_id;
+                // Otherwise nobody could ever change the fees.
+                contract.fee_to_setter.init(Self::env().caller());
+                contract.guardian = Self::env().caller();
+                contract.storage_version = STORAGE_VERSION;
+            })
         }
-
     }
   
     
 
     impl IUniswapV2Factory for UniswapV2Factory {
         
-        // #[ink(message)]                                                 //-> UniswapFactoryResult<()>
-        // fn create_pair(&mut self, token_a: AccountId, token_b: AccountId)  {
-                // if token_a != token_b {
-                //     // Conditional Operator (? :)
-                //     // The conditional operator first evaluates an expression for a true or false value and then executes 
-                //     // one of the two given statements depending upon the result of the evaluation.
-
-                //     if token_b > token_a {
-                //         let token0: AccountId = token_a;
-                //         let token1: AccountId = token_b;
-                //     }
-                //     else {
-                //         let token0: AccountId = token_b;
-                //         let token1: AccountId = token_a;
-                //     }
-                    
-                       
-                    
-                //     todo!();
-                    
-                // }
-        //         todo!();
-        // }
-        // #[ink(message)]
-        // fn all_pairs_length(&mut self)  {
-        //     // return self.all_pairs.length;
-        //     todo!();
-        // }
-        #[ink(message)]
-        fn set_fee_to(&mut self, _fee_to: AccountId) {
-            let sender = self.env().caller();
-            if sender == self.fee_to_setter {
-                self.fee_to = _fee_to;
+        #[ink(message)]
+        fn create_pair(&mut self, token_a: AccountId, token_b: AccountId) -> UniswapFactoryResult<AccountId> {
+            // Sorted like `uniswap_v2_library::pair_for` sorts them.
+            let (token0, token1) = sort_tokens(token_a, token_b).map_err(|error| match error {
+                LibraryError::IdenticalAddresses => UniswapFactoryError::IdenticalAddress,
+                LibraryError::ZeroAddress => UniswapFactoryError::ZeroAddress,
+                error => unreachable!("sort_tokens failed with {:?}", error),
+            })?;
+            if self.get_pair.get((&token0, &token1)).is_some() {
+                return Err(UniswapFactoryError::PairExists);
             }
+
+            let mut pair = UniswapV2PairRef::new(self.chain_id)
+                .code_hash(self.pair_code_hash)
+                .endowment(0)
+                // Pair addresses can thus be computed with `uniswap_v2_library::pair_for`.
+                .salt_bytes(pair_salt(&token0, &token1))
+                .instantiate()
+                .unwrap_or_else(|error| panic!("failed to instantiate pair: {:?}", error));
+            pair.initialize(token0, token1)
+                .expect("the factory may initialize the pairs it instantiates");
+            let pair = pair.to_account_id();
+
+            self.get_pair.insert((&token0, &token1), &pair);
+            self.get_pair.insert((&token1, &token0), &pair);
+            self.all_pairs.insert(self.all_pairs_length, &pair);
+            self.all_pairs_length += 1;
+            self.env().emit_event(PairCreated {
+                token0,
+                token1,
+                pair,
+                all_pairs_length: self.all_pairs_length,
+            });
+            Ok(pair)
         }
+
+        #[ink(message)]
+        fn all_pairs_length(&self) -> u64 {
+            self.all_pairs_length
+        }
+
         #[ink(message)]
-        fn set_fee_to_setter(&mut self, _fee_to_setter: AccountId) {
-            let sender = self.env().caller();
-            if sender == self.fee_to_setter {
-                self.fee_to_setter = _fee_to_setter;
+        fn fees(&self) -> Balance {
+            self.fees
+        }
+
+        #[ink(message)]
+        fn set_fees(&mut self, fees: Balance) -> UniswapFactoryResult<()> {
+            if self.fee_to_setter.ensure_owner(&self.env().caller()).is_err() {
+                return Err(UniswapFactoryError::Forbidden);
+            }
+            if fees >= FEE_DENOMINATOR {
+                return Err(UniswapFactoryError::InvalidFees);
+            }
+            let old_fees = self.fees;
+            self.fees = fees;
+            self.env().emit_event(FeesChanged {
+                old_fees,
+                new_fees: fees,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn set_fee_to(&mut self, fee_to: AccountId) -> UniswapFactoryResult<()> {
+            if self.fee_to_setter.ensure_owner(&self.env().caller()).is_err() {
+                return Err(UniswapFactoryError::Forbidden);
+            }
+            let old_fee_to = core::mem::replace(&mut self.fee_to, fee_to);
+            self.env().emit_event(FeeToChanged {
+                old_fee_to,
+                new_fee_to: fee_to,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn guardian(&self) -> AccountId {
+            self.guardian
+        }
+
+        #[ink(message)]
+        fn set_guardian(&mut self, guardian: AccountId) -> UniswapFactoryResult<()> {
+            if self.fee_to_setter.ensure_owner(&self.env().caller()).is_err() {
+                return Err(UniswapFactoryError::Forbidden);
             }
+            let old_guardian = core::mem::replace(&mut self.guardian, guardian);
+            self.env().emit_event(GuardianChanged {
+                old_guardian,
+                new_guardian: guardian,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn is_paused(&self, pair: AccountId) -> bool {
+            self.all_paused || self.paused.get(&pair).unwrap_or(false)
+        }
+
+        #[ink(message)]
+        fn set_paused(&mut self, pair: AccountId, paused: bool) -> UniswapFactoryResult<()> {
+            if self.env().caller() != self.guardian {
+                return Err(UniswapFactoryError::Forbidden);
+            }
+            if paused {
+                self.paused.insert(&pair, &true);
+            } else {
+                self.paused.remove(&pair);
+            }
+            self.env().emit_event(PauseChanged {
+                pair: Some(pair),
+                paused,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn set_all_paused(&mut self, paused: bool) -> UniswapFactoryResult<()> {
+            if self.env().caller() != self.guardian {
+                return Err(UniswapFactoryError::Forbidden);
+            }
+            self.all_paused = paused;
+            self.env().emit_event(PauseChanged { pair: None, paused });
+            Ok(())
         }
 
         #[ink(message)]
         fn fee_to(&self) -> AccountId {
-            todo!();
+            self.fee_to
         }
 
         #[ink(message)]
-        fn fee_to_setter(&self) -> AccountId {
-            todo!();
+        fn fee_to_setter(&self) -> Option<AccountId> {
+            self.fee_to_setter.owner()
         }
 
         #[ink(message)]
-        fn get_pair(&self, toekn_a: AccountId, token_b: AccountId) -> AccountId{
-            todo!();
+        fn get_pair(&self, token_a: AccountId, token_b: AccountId) -> Option<AccountId> {
+            self.get_pair.get((&token_a, &token_b))
         }
 
         #[ink(message)]
-        fn all_pairs(&self, log_value: u64) -> AccountId {
-            todo!();
+        fn all_pairs(&self, log_value: u64) -> Option<AccountId> {
+            self.all_pairs.get(log_value)
         }
 
     }
 
-    
-    
-      
-}
\ No newline at end of file
+    impl Ownable2Step for UniswapV2Factory {
+        #[ink(message)]
+        fn owner(&self) -> Option<AccountId> {
+            self.fee_to_setter.owner()
+        }
+
+        #[ink(message)]
+        fn pending_owner(&self) -> Option<AccountId> {
+            self.fee_to_setter.pending_owner()
+        }
+
+        #[ink(message)]
+        fn propose_owner(&mut self, new_owner: AccountId) -> OwnableResult<()> {
+            let owner = self.env().caller();
+            self.fee_to_setter.propose_owner(&owner, new_owner)?;
+            self.env().emit_event(OwnershipTransferStarted {
+                owner,
+                pending_owner: new_owner,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn accept_ownership(&mut self) -> OwnableResult<()> {
+            let new_owner = self.env().caller();
+            let previous_owner = self.fee_to_setter.accept_ownership(&new_owner)?;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: Some(new_owner),
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn renounce_ownership(&mut self) -> OwnableResult<()> {
+            let owner = self.env().caller();
+            self.fee_to_setter.renounce_ownership(&owner)?;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner: Some(owner),
+                new_owner: None,
+            });
+            Ok(())
+        }
+    }
+
+    impl Upgradeable for UniswapV2Factory {
+        #[ink(message)]
+        fn storage_version(&self) -> u32 {
+            self.storage_version
+        }
+
+        #[ink(message)]
+        fn upgrade(&mut self, code_hash: [u8; 32]) -> UpgradeResult<()> {
+            if self.fee_to_setter.ensure_owner(&self.env().caller()).is_err() {
+                return Err(UpgradeError::Forbidden);
+            }
+            ink_env::set_code_hash(&code_hash).map_err(|_| UpgradeError::CodeNotFound)?;
+            self.env().emit_event(Upgraded { code_hash });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn migrate(&mut self) -> UpgradeResult<()> {
+            if self.fee_to_setter.ensure_owner(&self.env().caller()).is_err() {
+                return Err(UpgradeError::Forbidden);
+            }
+            match self.storage_version {
+                STORAGE_VERSION => Ok(()),
+                version => Err(UpgradeError::UnknownStorageVersion(version)),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        type Event = <UniswapV2Factory as ::ink_lang::reflect::ContractEventBase>::Type;
+
+        fn accounts() -> ink_env::test::DefaultAccounts<ink_env::DefaultEnvironment> {
+            ink_env::test::default_accounts::<ink_env::DefaultEnvironment>()
+        }
+
+        /// A factory deployed by alice, who is thus its owner and guardian.
+        fn factory() -> UniswapV2Factory {
+            UniswapV2Factory::new(3, Hash::default(), Hash::default())
+        }
+
+        fn set_caller(caller: AccountId) {
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(caller);
+        }
+
+        /// The `(pair, paused)` of every `PauseChanged` emitted so far.
+        fn pause_changes() -> Vec<(Option<AccountId>, bool)> {
+            ink_env::test::recorded_events()
+                .filter_map(
+                    |event| match <Event as scale::Decode>::decode(&mut &event.data[..]) {
+                        Ok(Event::PauseChanged(change)) => Some((change.pair, change.paused)),
+                        _ => None,
+                    },
+                )
+                .collect()
+        }
+
+        #[ink::test]
+        fn only_owner_sets_fee_to() {
+            let mut factory = factory();
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            assert_eq!(
+                factory.set_fee_to(accounts.bob),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            set_caller(accounts.alice);
+            assert_eq!(factory.set_fee_to(accounts.bob), Ok(()));
+            assert_eq!(factory.fee_to(), accounts.bob);
+        }
+
+        #[ink::test]
+        fn only_owner_sets_fees() {
+            let mut factory = factory();
+            set_caller(accounts().bob);
+            assert_eq!(factory.set_fees(5), Err(UniswapFactoryError::Forbidden));
+            set_caller(accounts().alice);
+            assert_eq!(factory.set_fees(5), Ok(()));
+            assert_eq!(factory.fees(), 5);
+        }
+
+        #[ink::test]
+        fn only_owner_sets_guardian() {
+            let mut factory = factory();
+            let accounts = accounts();
+            set_caller(accounts.bob);
+            assert_eq!(
+                factory.set_guardian(accounts.bob),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            set_caller(accounts.alice);
+            assert_eq!(factory.set_guardian(accounts.bob), Ok(()));
+            assert_eq!(factory.guardian(), accounts.bob);
+        }
+
+        #[ink::test]
+        fn previous_owner_is_forbidden_after_handover() {
+            let mut factory = factory();
+            let accounts = accounts();
+            assert_eq!(factory.propose_owner(accounts.bob), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(factory.accept_ownership(), Ok(()));
+
+            set_caller(accounts.alice);
+            assert_eq!(
+                factory.set_fee_to(accounts.alice),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(factory.set_fee_to(accounts.bob), Ok(()));
+        }
+
+        #[ink::test]
+        fn only_guardian_pauses() {
+            let mut factory = factory();
+            let accounts = accounts();
+            let pair = accounts.django;
+            set_caller(accounts.bob);
+            assert_eq!(
+                factory.set_paused(pair, true),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            assert_eq!(
+                factory.set_all_paused(true),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            assert!(!factory.is_paused(pair));
+            assert!(pause_changes().is_empty());
+        }
+
+        #[ink::test]
+        fn pausing_pair_emits_pause_changed() {
+            let mut factory = factory();
+            let pair = accounts().django;
+            assert_eq!(factory.set_paused(pair, true), Ok(()));
+            assert!(factory.is_paused(pair));
+            assert!(!factory.is_paused(accounts().eve));
+            assert_eq!(factory.set_paused(pair, false), Ok(()));
+            assert!(!factory.is_paused(pair));
+            assert_eq!(pause_changes(), [(Some(pair), true), (Some(pair), false)]);
+        }
+
+        #[ink::test]
+        fn unpausing_pair_removes_its_entry() {
+            let mut factory = factory();
+            let pair = accounts().django;
+            factory.set_paused(pair, true).unwrap();
+            factory.set_paused(pair, false).unwrap();
+            assert_eq!(factory.paused.get(&pair), None);
+        }
+
+        #[ink::test]
+        fn all_paused_overrides_unpaused_pair() {
+            let mut factory = factory();
+            let pair = accounts().django;
+            assert_eq!(factory.set_all_paused(true), Ok(()));
+            factory.set_paused(pair, true).unwrap();
+            factory.set_paused(pair, false).unwrap();
+            assert!(factory.is_paused(pair));
+            assert_eq!(factory.set_all_paused(false), Ok(()));
+            assert!(!factory.is_paused(pair));
+            assert_eq!(
+                pause_changes(),
+                [
+                    (None, true),
+                    (Some(pair), true),
+                    (Some(pair), false),
+                    (None, false)
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn new_guardian_pauses() {
+            let mut factory = factory();
+            let accounts = accounts();
+            factory.set_guardian(accounts.bob).unwrap();
+            assert_eq!(
+                factory.set_all_paused(true),
+                Err(UniswapFactoryError::Forbidden)
+            );
+            set_caller(accounts.bob);
+            assert_eq!(factory.set_all_paused(true), Ok(()));
+        }
+
+        #[ink::test]
+        fn only_owner_upgrades() {
+            let mut factory = factory();
+            set_caller(accounts().bob);
+            assert_eq!(factory.upgrade([1; 32]), Err(UpgradeError::Forbidden));
+            assert_eq!(factory.migrate(), Err(UpgradeError::Forbidden));
+        }
+
+        #[ink::test]
+        fn migrating_current_layout_does_nothing() {
+            let mut factory = factory();
+            assert_eq!(factory.migrate(), Ok(()));
+            assert_eq!(factory.storage_version(), STORAGE_VERSION);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! The AMM math of Uniswap v2, over plain integers.
+//!
+//! Shared by the contracts and by off-chain code, so that both compute the
+//! same amounts. Builds without std, for use in contracts.
+//!
+//! # References
+//!
+//! - <https://github.com/Uniswap/v2-periphery/blob/master/contracts/libraries/UniswapV2Library.sol>
+//! - <https://github.com/Uniswap/v2-core/blob/master/contracts/libraries/Math.sol>
+//! - <https://docs.uniswap.org/protocol/V2/reference/smart-contracts/library>
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_env::hash::{Blake2x256, HashOutput};
+use ink_env::{AccountId, Hash};
+use ink_prelude::vec::Vec;
+use primitive_types::U256;
+
+pub type Balance = u128;
+
+/// Swap fees are expressed in thousandths, e.g. 3 for Uniswap's 0.3%.
+///
+/// Fees must be less than this.
+pub const FEE_DENOMINATOR: u128 = 1_000;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LibraryError {
+    IdenticalAddresses,
+    ZeroAddress,
+    InsufficientAmount,
+    InsufficientInputAmount,
+    InsufficientOutputAmount,
+    InsufficientLiquidity,
+    /// A swap path has fewer than two tokens.
+    InvalidPath,
+    /// A fee isn't less than the fee denominator.
+    InvalidFee,
+    /// A result doesn't fit in a `Balance`.
+    Overflow,
+}
+
+pub type LibraryResult<T> = core::result::Result<T, LibraryError>;
+
+/// Orders two tokens the way their pair does, as `(token0, token1)`.
+pub fn sort_tokens(
+    token_a: AccountId,
+    token_b: AccountId,
+) -> LibraryResult<(AccountId, AccountId)> {
+    if token_a == token_b {
+        return Err(LibraryError::IdenticalAddresses);
+    }
+    let (token0, token1) = if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    };
+    // Sorting puts the zero address first, so one check suffices.
+    if token0 == AccountId::from([0; 32]) {
+        return Err(LibraryError::ZeroAddress);
+    }
+    Ok((token0, token1))
+}
+
+/// The salt the factory instantiates the pair of two sorted tokens with.
+pub fn pair_salt(token0: &AccountId, token1: &AccountId) -> [u8; 64] {
+    let mut salt = [0; 64];
+    salt[..32].copy_from_slice(token0.as_ref());
+    salt[32..].copy_from_slice(token1.as_ref());
+    salt
+}
+
+/// Computes the address of the pair of two tokens, without any calls.
+///
+/// This is the address `pallet-contracts` gives a contract instantiated by
+/// `factory` from `pair_code_hash` with the pair's salt: the BLAKE2 hash of
+/// the three concatenated. It must be kept in line with the address
+/// derivation of the node the contracts are deployed on.
+pub fn pair_for(
+    factory: &AccountId,
+    pair_code_hash: &Hash,
+    token_a: AccountId,
+    token_b: AccountId,
+) -> LibraryResult<AccountId> {
+    let (token0, token1) = sort_tokens(token_a, token_b)?;
+    let mut input = Vec::with_capacity(32 + 32 + 64);
+    input.extend_from_slice(factory.as_ref());
+    input.extend_from_slice(pair_code_hash.as_ref());
+    input.extend_from_slice(&pair_salt(&token0, &token1));
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink_env::hash_bytes::<Blake2x256>(&input, &mut output);
+    Ok(AccountId::from(output))
+}
+
+/// The share of an input left after the fee, in thousandths.
+fn fee_complement(fee: u128) -> LibraryResult<u128> {
+    FEE_DENOMINATOR
+        .checked_sub(fee)
+        .filter(|&complement| complement > 0)
+        .ok_or(LibraryError::InvalidFee)
+}
+
+fn to_balance(value: U256) -> LibraryResult<Balance> {
+    Balance::try_from(value).map_err(|_| LibraryError::Overflow)
+}
+
+/// The integer square root, computed with the Babylonian method
+/// as in Uniswap's `Math.sqrt`.
+pub fn sqrt(y: U256) -> U256 {
+    if y > U256::from(3) {
+        let mut z = y;
+        let mut x = y / 2 + 1;
+        while x < z {
+            z = x;
+            x = (y / x + x) / 2;
+        }
+        z
+    } else if !y.is_zero() {
+        U256::one()
+    } else {
+        U256::zero()
+    }
+}
+
+/// Returns the amount of token B worth `amount_a` of token A,
+/// at the price given by the reserves.
+pub fn quote(amount_a: Balance, reserve_a: Balance, reserve_b: Balance) -> LibraryResult<Balance> {
+    if amount_a == 0 {
+        return Err(LibraryError::InsufficientAmount);
+    }
+    if reserve_a == 0 || reserve_b == 0 {
+        return Err(LibraryError::InsufficientLiquidity);
+    }
+    to_balance(U256::from(amount_a) * U256::from(reserve_b) / U256::from(reserve_a))
+}
+
+/// Returns how much of the other token swapping `amount_in` yields,
+/// after the fee, in thousandths, at the price given by the reserves.
+pub fn get_amount_out(
+    amount_in: Balance,
+    reserve_in: Balance,
+    reserve_out: Balance,
+    fee: u128,
+) -> LibraryResult<Balance> {
+    if amount_in == 0 {
+        return Err(LibraryError::InsufficientInputAmount);
+    }
+    if reserve_in == 0 || reserve_out == 0 {
+        return Err(LibraryError::InsufficientLiquidity);
+    }
+    let amount_in_with_fee = U256::from(amount_in) * U256::from(fee_complement(fee)?);
+    let numerator = amount_in_with_fee * U256::from(reserve_out);
+    let denominator = U256::from(reserve_in) * U256::from(FEE_DENOMINATOR) + amount_in_with_fee;
+    to_balance(numerator / denominator)
+}
+
+/// Returns how much of the other token swapping for `amount_out` takes,
+/// including the fee, in thousandths, at the price given by the reserves.
+pub fn get_amount_in(
+    amount_out: Balance,
+    reserve_in: Balance,
+    reserve_out: Balance,
+    fee: u128,
+) -> LibraryResult<Balance> {
+    if amount_out == 0 {
+        return Err(LibraryError::InsufficientOutputAmount);
+    }
+    if reserve_in == 0 || amount_out >= reserve_out {
+        return Err(LibraryError::InsufficientLiquidity);
+    }
+    let numerator = U256::from(reserve_in) * U256::from(amount_out) * U256::from(FEE_DENOMINATOR);
+    let denominator = U256::from(reserve_out - amount_out) * U256::from(fee_complement(fee)?);
+    to_balance(numerator / denominator + 1)
+}
+
+/// Returns the amounts along a swap path of swapping `amount_in`,
+/// given the `(reserve_in, reserve_out)` of each hop.
+pub fn get_amounts_out(
+    amount_in: Balance,
+    reserves: &[(Balance, Balance)],
+    fee: u128,
+) -> LibraryResult<Vec<Balance>> {
+    if reserves.is_empty() {
+        return Err(LibraryError::InvalidPath);
+    }
+    let mut amounts = Vec::with_capacity(reserves.len() + 1);
+    amounts.push(amount_in);
+    for &(reserve_in, reserve_out) in reserves {
+        let amount_out = get_amount_out(amounts[amounts.len() - 1], reserve_in, reserve_out, fee)?;
+        amounts.push(amount_out);
+    }
+    Ok(amounts)
+}
+
+/// Returns the amounts along a swap path of swapping for `amount_out`,
+/// given the `(reserve_in, reserve_out)` of each hop.
+pub fn get_amounts_in(
+    amount_out: Balance,
+    reserves: &[(Balance, Balance)],
+    fee: u128,
+) -> LibraryResult<Vec<Balance>> {
+    if reserves.is_empty() {
+        return Err(LibraryError::InvalidPath);
+    }
+    let mut amounts = ink_prelude::vec![0; reserves.len() + 1];
+    amounts[reserves.len()] = amount_out;
+    for (i, &(reserve_in, reserve_out)) in reserves.iter().enumerate().rev() {
+        amounts[i] = get_amount_in(amounts[i + 1], reserve_in, reserve_out, fee)?;
+    }
+    Ok(amounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const E18: Balance = 1_000_000_000_000_000_000;
+
+    /// Uniswap's default fee of 0.3%.
+    const FEE: u128 = 3;
+
+    fn account(byte: u8) -> AccountId {
+        AccountId::from([byte; 32])
+    }
+
+    #[test]
+    fn sqrt_rounds_down() {
+        assert_eq!(sqrt(U256::zero()), U256::zero());
+        assert_eq!(sqrt(U256::from(1)), U256::one());
+        assert_eq!(sqrt(U256::from(3)), U256::one());
+        assert_eq!(sqrt(U256::from(4)), U256::from(2));
+        assert_eq!(sqrt(U256::from(10)), U256::from(3));
+        assert_eq!(sqrt(U256::from(E18) * U256::from(E18)), U256::from(E18));
+        assert_eq!(sqrt(U256::MAX), U256::from(u128::MAX));
+    }
+
+    #[test]
+    fn quote_is_proportional() {
+        assert_eq!(quote(1, 100, 200), Ok(2));
+        assert_eq!(quote(2, 200, 100), Ok(1));
+        assert_eq!(quote(0, 100, 200), Err(LibraryError::InsufficientAmount));
+        assert_eq!(quote(1, 0, 200), Err(LibraryError::InsufficientLiquidity));
+    }
+
+    #[test]
+    fn get_amount_out_matches_uniswap() {
+        // The swap test cases of Uniswap's `UniswapV2Pair.spec.ts`.
+        let cases = [
+            (E18, 5 * E18, 10 * E18, 1_662_497_915_624_478_906),
+            (E18, 10 * E18, 5 * E18, 453_305_446_940_074_565),
+            (2 * E18, 5 * E18, 10 * E18, 2_851_015_155_847_869_602),
+            (E18, 100 * E18, 100 * E18, 987_158_034_397_061_298),
+        ];
+        for (amount_in, reserve_in, reserve_out, amount_out) in cases {
+            assert_eq!(
+                get_amount_out(amount_in, reserve_in, reserve_out, FEE),
+                Ok(amount_out)
+            );
+        }
+        assert_eq!(get_amount_out(2, 100, 100, FEE), Ok(1));
+        assert_eq!(
+            get_amount_out(0, 100, 100, FEE),
+            Err(LibraryError::InsufficientInputAmount)
+        );
+        assert_eq!(
+            get_amount_out(2, 100, 100, FEE_DENOMINATOR),
+            Err(LibraryError::InvalidFee)
+        );
+    }
+
+    #[test]
+    fn get_amount_in_matches_uniswap() {
+        assert_eq!(get_amount_in(1, 100, 100, FEE), Ok(2));
+        assert_eq!(
+            get_amount_in(0, 100, 100, FEE),
+            Err(LibraryError::InsufficientOutputAmount)
+        );
+        assert_eq!(
+            get_amount_in(100, 100, 100, FEE),
+            Err(LibraryError::InsufficientLiquidity)
+        );
+    }
+
+    #[test]
+    fn get_amounts_round_trip() {
+        let reserves = [(5 * E18, 10 * E18), (10 * E18, 20 * E18)];
+        let amounts_out = get_amounts_out(E18, &reserves, FEE).unwrap();
+        assert_eq!(
+            amounts_out,
+            [E18, 1_662_497_915_624_478_906, 2_843_678_215_834_080_602]
+        );
+        let amounts_in = get_amounts_in(amounts_out[2], &reserves, FEE).unwrap();
+        assert_eq!(amounts_in, amounts_out);
+        assert_eq!(
+            get_amounts_out(E18, &[], FEE),
+            Err(LibraryError::InvalidPath)
+        );
+        assert_eq!(
+            get_amounts_in(E18, &[], FEE),
+            Err(LibraryError::InvalidPath)
+        );
+    }
+
+    #[test]
+    fn sort_tokens_orders_and_rejects() {
+        assert_eq!(
+            sort_tokens(account(2), account(1)),
+            Ok((account(1), account(2)))
+        );
+        assert_eq!(
+            sort_tokens(account(1), account(2)),
+            Ok((account(1), account(2)))
+        );
+        assert_eq!(
+            sort_tokens(account(1), account(1)),
+            Err(LibraryError::IdenticalAddresses)
+        );
+        assert_eq!(
+            sort_tokens(account(0), account(1)),
+            Err(LibraryError::ZeroAddress)
+        );
+    }
+
+    #[test]
+    fn pair_for_is_fixed() {
+        let expected = AccountId::from([
+            0xf2, 0x20, 0x89, 0xd9, 0xe1, 0x1f, 0x10, 0xca, 0x93, 0x95, 0x83, 0xcb, 0x03, 0xbc,
+            0x57, 0xa3, 0xa5, 0x7b, 0x11, 0x63, 0x74, 0x5c, 0x5d, 0x63, 0x7f, 0xb5, 0xc2, 0x28,
+            0x0f, 0x19, 0x74, 0x39,
+        ]);
+        let factory = account(1);
+        let pair_code_hash = Hash::from([2; 32]);
+        assert_eq!(
+            pair_for(&factory, &pair_code_hash, account(3), account(4)),
+            Ok(expected)
+        );
+        assert_eq!(
+            pair_for(&factory, &pair_code_hash, account(4), account(3)),
+            Ok(expected)
+        );
+    }
+}
@@ -0,0 +1,804 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+pub use self::uniswap_v2_pair::{UniswapV2Pair, UniswapV2PairRef};
+
+#[ink::contract]
+mod uniswap_v2_pair {
+    use ink_prelude::string::{String, ToString};
+    use ink_prelude::vec::Vec;
+    use ink_primitives::Key;
+    use ink_storage::traits::SpreadAllocate;
+    use swap_traits::{
+        Erc20Error, Erc20Ref, Erc20Result, IUniswapV2CalleeRef, IUniswapV2FactoryRef,
+        IUniswapV2Pair, UQ112x112, UniswapPairError, UniswapPairResult, UpgradeError,
+        UpgradeResult, Upgradeable, U256,
+    };
+    use uniswap_v2_erc20::Erc20Data;
+    use uniswap_v2_library::{sqrt, FEE_DENOMINATOR};
+
+    const NAME: &str = "Uniswap V2";
+    const SYMBOL: &str = "UNI-V2";
+    const DECIMALS: u8 = 18;
+
+    /// Liquidity locked forever on the first mint,
+    /// so that the pool can never be fully drained.
+    const MINIMUM_LIQUIDITY: Balance = 1_000;
+
+    /// Reserves are kept within 112 bits, like Solidity's `uint112`,
+    /// so that products of two of them, times the fee denominator squared,
+    /// fit in 256 bits.
+    const MAX_RESERVE: Balance = (1 << 112) - 1;
+
+    /// Where the reentrancy lock is stored, apart from the storage struct.
+    ///
+    /// ink! loads the storage struct when a message starts and writes it back
+    /// only when the message returns, so a lock kept in it would still read
+    /// as released to a call re-entering the pair.
+    const LOCK_KEY: [u8; 32] = *b"uniswap_v2_pair::locked\0\0\0\0\0\0\0\0\0";
+
+    /// The version of the storage layout below. Bump it, and teach
+    /// `migrate` the previous one, whenever the layout changes.
+    ///
+    /// Only layouts that add `Mapping` fields after the existing ones can be
+    /// migrated to, as the other fields are loaded before `migrate` runs.
+    const STORAGE_VERSION: u32 = 1;
+
+    #[ink(storage)]
+    #[derive(SpreadAllocate)]
+    pub struct UniswapV2Pair {
+        /// The version of the layout the storage is in.
+        ///
+        /// Must stay the first field, so that its key is the same in every
+        /// layout.
+        storage_version: u32,
+        factory: AccountId,
+        token0: AccountId,
+        token1: AccountId,
+        reserve0: Balance,
+        reserve1: Balance,
+        block_timestamp_last: u32,
+        price0_cumulative_last: UQ112x112,
+        price1_cumulative_last: UQ112x112,
+        /// `reserve0 * reserve1`, as of immediately after the most recent
+        /// liquidity event, while the protocol fee is on.
+        ///
+        /// Kept as the little-endian bytes of a `U256`,
+        /// which has no storage layout of its own.
+        k_last: [u8; 32],
+        /// The liquidity tokens.
+        erc20: Erc20Data,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    /// Liquidity was added.
+    #[ink(event)]
+    pub struct Mint {
+        #[ink(topic)]
+        sender: AccountId,
+        amount0: Balance,
+        amount1: Balance,
+    }
+
+    /// Liquidity was removed, and paid out to `to`.
+    #[ink(event)]
+    pub struct Burn {
+        #[ink(topic)]
+        sender: AccountId,
+        amount0: Balance,
+        amount1: Balance,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Swap {
+        #[ink(topic)]
+        sender: AccountId,
+        amount0_in: Balance,
+        amount1_in: Balance,
+        amount0_out: Balance,
+        amount1_out: Balance,
+        #[ink(topic)]
+        to: AccountId,
+    }
+
+    /// The reserves were updated, by any of the messages that change them.
+    #[ink(event)]
+    pub struct Sync {
+        reserve0: Balance,
+        reserve1: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Upgraded {
+        code_hash: [u8; 32],
+    }
+
+    impl UniswapV2Pair {
+        /// Creates an uninitialized pair owned by the calling factory,
+        /// whose liquidity tokens can be permitted on the chain `chain_id`.
+        #[ink(constructor)]
+        pub fn new(chain_id: Hash) -> Self {
+            ink_lang::utils::initialize_contract(|contract: &mut Self| {
+                contract.factory = Self::env().caller();
+                contract.storage_version = STORAGE_VERSION;
+                contract
+                    .erc20
+                    .init_domain_separator(NAME, chain_id, &Self::env().account_id());
+            })
+        }
+
+        /// Runs `f` unless it would re-enter another locked call,
+        /// like Uniswap's `lock` modifier.
+        ///
+        /// `pallet-contracts` already refuses calls that re-enter a contract,
+        /// unless the call out of it allowed re-entry, which only the pair
+        /// itself could do. As neither `Erc20Ref` nor `IUniswapV2CalleeRef`
+        /// does, the lock can't trigger on-chain today: it is defence in depth,
+        /// should a call out of the pair ever allow re-entry. It is written to
+        /// storage at once, before `f` calls out of the pair.
+        fn lock<T>(
+            &mut self,
+            f: impl FnOnce(&mut Self) -> UniswapPairResult<T>,
+        ) -> UniswapPairResult<T> {
+            if is_locked() {
+                return Err(UniswapPairError::Locked);
+            }
+            set_locked(true);
+            // Reverting calls also revert taking the lock.
+            let result = f(self);
+            set_locked(false);
+            result
+        }
+
+        /// Returns this contract's balance of `token`.
+        fn token_balance(&self, token: AccountId) -> Balance {
+            Erc20Ref::new(token).balance_of(self.env().account_id())
+        }
+
+        fn safe_transfer(
+            &self,
+            token: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> UniswapPairResult<()> {
+            Erc20Ref::new(token).transfer(to, value)?;
+            Ok(())
+        }
+
+        fn get_k_last(&self) -> U256 {
+            U256::from_little_endian(&self.k_last)
+        }
+
+        fn set_k_last(&mut self, k_last: U256) {
+            k_last.to_little_endian(&mut self.k_last);
+        }
+
+        /// Stores new reserves and, on the first update in a block,
+        /// accumulates the prices that held since the last one.
+        fn update(&mut self, balance0: Balance, balance1: Balance) -> UniswapPairResult<()> {
+            if balance0 > MAX_RESERVE || balance1 > MAX_RESERVE {
+                return Err(UniswapPairError::Overflow);
+            }
+            let (reserve0, reserve1) = (self.reserve0, self.reserve1);
+            // Solidity truncates `block.timestamp` (seconds) to 32 bits;
+            // ink! timestamps are milliseconds.
+            let block_timestamp = (self.env().block_timestamp() / 1000) as u32;
+            // Overflow is desired.
+            let time_elapsed = block_timestamp.wrapping_sub(self.block_timestamp_last);
+            if time_elapsed > 0 && reserve0 != 0 && reserve1 != 0 {
+                let price0 = UQ112x112::encode(reserve1).uqdiv(reserve0);
+                let price1 = UQ112x112::encode(reserve0).uqdiv(reserve1);
+                self.price0_cumulative_last = self
+                    .price0_cumulative_last
+                    .wrapping_add(price0.wrapping_mul(time_elapsed));
+                self.price1_cumulative_last = self
+                    .price1_cumulative_last
+                    .wrapping_add(price1.wrapping_mul(time_elapsed));
+            }
+
+            self.reserve0 = balance0;
+            self.reserve1 = balance1;
+            self.block_timestamp_last = block_timestamp;
+            self.env().emit_event(Sync {
+                reserve0: balance0,
+                reserve1: balance1,
+            });
+            Ok(())
+        }
+
+        fn ensure_not_paused(&self) -> UniswapPairResult<()> {
+            if IUniswapV2FactoryRef::new(self.factory).is_paused(self.env().account_id()) {
+                return Err(UniswapPairError::Paused);
+            }
+            Ok(())
+        }
+
+        /// Pairs are upgraded by the owner of their factory.
+        fn ensure_factory_owner(&self) -> UpgradeResult<()> {
+            let owner = IUniswapV2FactoryRef::new(self.factory).fee_to_setter();
+            if owner != Some(self.env().caller()) {
+                return Err(UpgradeError::Forbidden);
+            }
+            Ok(())
+        }
+
+        /// Mints the protocol fee, one sixth of the growth in `sqrt(k)`
+        /// since the last liquidity event, to the factory's `fee_to`.
+        ///
+        /// Returns whether the protocol fee is on.
+        fn mint_fee(&mut self, reserve0: Balance, reserve1: Balance) -> UniswapPairResult<bool> {
+            let fee_to = IUniswapV2FactoryRef::new(self.factory).fee_to();
+            let fee_on = fee_to != AccountId::from([0; 32]);
+            let k_last = self.get_k_last();
+            if fee_on {
+                if !k_last.is_zero() {
+                    let root_k = sqrt(U256::from(reserve0) * U256::from(reserve1));
+                    let root_k_last = sqrt(k_last);
+                    if root_k > root_k_last {
+                        let numerator =
+                            U256::from(self.erc20.total_supply) * (root_k - root_k_last);
+                        let denominator = root_k * U256::from(5) + root_k_last;
+                        let liquidity = to_balance(numerator / denominator)?;
+                        if liquidity > 0 {
+                            self.mint_liquidity(&fee_to, liquidity)?;
+                        }
+                    }
+                }
+            } else if !k_last.is_zero() {
+                self.set_k_last(U256::zero());
+            }
+            Ok(fee_on)
+        }
+
+        fn mint_liquidity(&mut self, to: &AccountId, value: Balance) -> Erc20Result<()> {
+            self.erc20.mint(to, value)?;
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(*to),
+                value,
+            });
+            Ok(())
+        }
+
+        fn burn_liquidity(&mut self, from: &AccountId, value: Balance) -> Erc20Result<()> {
+            self.erc20.burn(from, value)?;
+            self.env().emit_event(Transfer {
+                from: Some(*from),
+                to: None,
+                value,
+            });
+            Ok(())
+        }
+
+        fn transfer_liquidity(
+            &mut self,
+            from: &AccountId,
+            to: &AccountId,
+            value: Balance,
+        ) -> Erc20Result<()> {
+            self.erc20.transfer_from_to(from, to, value)?;
+            self.env().emit_event(Transfer {
+                from: Some(*from),
+                to: Some(*to),
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    /// Reverts the call with `error` as a `UniswapPairError`.
+    fn revert<E: Into<UniswapPairError>, T>(error: E) -> T {
+        swap_traits::revert::<UniswapPairError, T>(error.into())
+    }
+
+    fn is_locked() -> bool {
+        matches!(
+            ink_env::get_contract_storage::<bool>(&Key::from(LOCK_KEY)),
+            Ok(Some(true))
+        )
+    }
+
+    fn set_locked(locked: bool) {
+        let key = Key::from(LOCK_KEY);
+        if locked {
+            ink_env::set_contract_storage(&key, &true);
+        } else {
+            ink_env::clear_contract_storage(&key);
+        }
+    }
+
+    fn to_balance(value: U256) -> UniswapPairResult<Balance> {
+        Balance::try_from(value).map_err(|_| UniswapPairError::Overflow)
+    }
+
+    impl IUniswapV2Pair for UniswapV2Pair {
+        #[ink(message)]
+        fn name(&self) -> String {
+            NAME.to_string()
+        }
+
+        #[ink(message)]
+        fn symbol(&self) -> String {
+            SYMBOL.to_string()
+        }
+
+        #[ink(message)]
+        fn decimals(&self) -> u8 {
+            DECIMALS
+        }
+
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.erc20.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.erc20.balance_of(&owner)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.erc20.allowance(&owner, &spender)
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> UniswapPairResult<()> {
+            let owner = self.env().caller();
+            self.erc20.approve(&owner, &spender, value);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: Balance) -> UniswapPairResult<()> {
+            let from = self.env().caller();
+            self.transfer_liquidity(&from, &to, value)?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> UniswapPairResult<()> {
+            let caller = self.env().caller();
+            if self.erc20.allowance(&from, &caller) < value {
+                return Err(Erc20Error::InsufficientAllowance.into());
+            }
+            self.transfer_liquidity(&from, &to, value)?;
+            if let Some(allowance) = self.erc20.spend_allowance(&from, &caller, value)? {
+                self.env().emit_event(Approval {
+                    owner: from,
+                    spender: caller,
+                    value: allowance,
+                });
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn domain_separator(&self) -> [u8; 32] {
+            self.erc20.domain_separator
+        }
+
+        #[ink(message)]
+        fn permit_typehash(&self) -> [u8; 32] {
+            self.erc20.permit_typehash()
+        }
+
+        #[ink(message)]
+        fn nonces(&self, owner: AccountId) -> u64 {
+            self.erc20.nonces(&owner)
+        }
+
+        #[ink(message)]
+        fn permit(
+            &mut self,
+            owner: AccountId,
+            spender: AccountId,
+            value: Balance,
+            deadline: u64,
+            v: u8,
+            r: [u8; 32],
+            s: [u8; 32],
+        ) -> UniswapPairResult<()> {
+            self.erc20
+                .permit(&owner, &spender, value, deadline, v, r, s)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn minimum_liquidity(&self) -> Balance {
+            MINIMUM_LIQUIDITY
+        }
+
+        #[ink(message)]
+        fn factory(&self) -> AccountId {
+            self.factory
+        }
+
+        #[ink(message)]
+        fn token0(&self) -> AccountId {
+            self.token0
+        }
+
+        #[ink(message)]
+        fn token1(&self) -> AccountId {
+            self.token1
+        }
+
+        #[ink(message)]
+        fn get_reserves(&self) -> (Balance, Balance, u32) {
+            (self.reserve0, self.reserve1, self.block_timestamp_last)
+        }
+
+        #[ink(message)]
+        fn price_0_cumulative_last(&self) -> UQ112x112 {
+            self.price0_cumulative_last
+        }
+
+        #[ink(message)]
+        fn price_1_cumulative_last(&self) -> UQ112x112 {
+            self.price1_cumulative_last
+        }
+
+        #[ink(message)]
+        fn k_last(&self) -> U256 {
+            self.get_k_last()
+        }
+
+        #[ink(message)]
+        fn mint(&mut self, to: AccountId) -> UniswapPairResult<Balance> {
+            self.lock(|pair| {
+                pair.ensure_not_paused()?;
+                let (reserve0, reserve1) = (pair.reserve0, pair.reserve1);
+                let balance0 = pair.token_balance(pair.token0);
+                let balance1 = pair.token_balance(pair.token1);
+                let amount0 = balance0
+                    .checked_sub(reserve0)
+                    .ok_or(UniswapPairError::Overflow)?;
+                let amount1 = balance1
+                    .checked_sub(reserve1)
+                    .ok_or(UniswapPairError::Overflow)?;
+
+                let fee_on = pair.mint_fee(reserve0, reserve1)?;
+                // `mint_fee` may have minted, so errors revert from here on.
+                // Must be read after `mint_fee` for the same reason.
+                let total_supply = pair.erc20.total_supply;
+                let liquidity = if total_supply == 0 {
+                    let liquidity = to_balance(sqrt(U256::from(amount0) * U256::from(amount1)))
+                        .unwrap_or_else(revert)
+                        .checked_sub(MINIMUM_LIQUIDITY)
+                        .unwrap_or_else(|| revert(UniswapPairError::InsufficientLiquidity));
+                    // Nobody holds the keys to the zero account.
+                    pair.mint_liquidity(&AccountId::from([0; 32]), MINIMUM_LIQUIDITY)
+                        .unwrap_or_else(revert);
+                    liquidity
+                } else {
+                    let total_supply = U256::from(total_supply);
+                    let liquidity0 = U256::from(amount0) * total_supply / U256::from(reserve0);
+                    let liquidity1 = U256::from(amount1) * total_supply / U256::from(reserve1);
+                    to_balance(liquidity0.min(liquidity1)).unwrap_or_else(revert)
+                };
+                if liquidity == 0 {
+                    revert(UniswapPairError::InsufficientLiquidity)
+                }
+                pair.mint_liquidity(&to, liquidity).unwrap_or_else(revert);
+
+                pair.update(balance0, balance1).unwrap_or_else(revert);
+                if fee_on {
+                    pair.set_k_last(U256::from(pair.reserve0) * U256::from(pair.reserve1));
+                }
+                pair.env().emit_event(Mint {
+                    sender: pair.env().caller(),
+                    amount0,
+                    amount1,
+                });
+                Ok(liquidity)
+            })
+        }
+
+        #[ink(message)]
+        fn burn(&mut self, to: AccountId) -> UniswapPairResult<(Balance, Balance)> {
+            self.lock(|pair| {
+                let this = pair.env().account_id();
+                let (token0, token1) = (pair.token0, pair.token1);
+                let balance0 = pair.token_balance(token0);
+                let balance1 = pair.token_balance(token1);
+                // Liquidity is sent to the pair before burning it.
+                let liquidity = pair.erc20.balance_of(&this);
+                if liquidity == 0 {
+                    return Err(UniswapPairError::InsufficientLiquidity);
+                }
+
+                let fee_on = pair.mint_fee(pair.reserve0, pair.reserve1)?;
+                // `mint_fee` may have minted, so errors revert from here on.
+                // Pay out the burned share of the pair's balances.
+                // Must be read after `mint_fee`, which can mint.
+                let total_supply = U256::from(pair.erc20.total_supply);
+                let liquidity_share = |balance: Balance| {
+                    to_balance(U256::from(liquidity) * U256::from(balance) / total_supply)
+                        .unwrap_or_else(revert)
+                };
+                let amount0 = liquidity_share(balance0);
+                let amount1 = liquidity_share(balance1);
+                if amount0 == 0 || amount1 == 0 {
+                    revert(UniswapPairError::InsufficientLiquidity)
+                }
+
+                pair.burn_liquidity(&this, liquidity).unwrap_or_else(revert);
+                pair.safe_transfer(token0, to, amount0)
+                    .unwrap_or_else(revert);
+                pair.safe_transfer(token1, to, amount1)
+                    .unwrap_or_else(revert);
+
+                let balance0 = pair.token_balance(token0);
+                let balance1 = pair.token_balance(token1);
+                pair.update(balance0, balance1).unwrap_or_else(revert);
+                if fee_on {
+                    pair.set_k_last(U256::from(pair.reserve0) * U256::from(pair.reserve1));
+                }
+                pair.env().emit_event(Burn {
+                    sender: pair.env().caller(),
+                    amount0,
+                    amount1,
+                    to,
+                });
+                Ok((amount0, amount1))
+            })
+        }
+
+        #[ink(message)]
+        fn swap(
+            &mut self,
+            amount0_out: Balance,
+            amount1_out: Balance,
+            to: AccountId,
+            data: Vec<u8>,
+        ) -> UniswapPairResult<()> {
+            self.lock(|pair| {
+                pair.ensure_not_paused()?;
+                if amount0_out == 0 && amount1_out == 0 {
+                    return Err(UniswapPairError::InsufficientOutputAmount);
+                }
+                let (reserve0, reserve1) = (pair.reserve0, pair.reserve1);
+                if amount0_out >= reserve0 || amount1_out >= reserve1 {
+                    return Err(UniswapPairError::InsufficientLiquidity);
+                }
+                if to == pair.token0 || to == pair.token1 {
+                    return Err(UniswapPairError::InvalidTo);
+                }
+
+                // Tokens leave the pair here, so errors revert from here on.
+                if amount0_out > 0 {
+                    pair.safe_transfer(pair.token0, to, amount0_out)
+                        .unwrap_or_else(revert);
+                }
+                if amount1_out > 0 {
+                    pair.safe_transfer(pair.token1, to, amount1_out)
+                        .unwrap_or_else(revert);
+                }
+                // A flash swap: `to` may use the outputs before paying for them,
+                // as long as the invariant below holds once it returns.
+                if !data.is_empty() {
+                    let sender = pair.env().caller();
+                    IUniswapV2CalleeRef::new(to).uniswap_v2_call(
+                        sender,
+                        amount0_out,
+                        amount1_out,
+                        data,
+                    );
+                }
+                let balance0 = pair.token_balance(pair.token0);
+                let balance1 = pair.token_balance(pair.token1);
+
+                let amount0_in = balance0.saturating_sub(reserve0 - amount0_out);
+                let amount1_in = balance1.saturating_sub(reserve1 - amount1_out);
+                if amount0_in == 0 && amount1_in == 0 {
+                    revert(UniswapPairError::InsufficientInputAmount)
+                }
+                // Also keeps the products below within 256 bits.
+                if balance0 > MAX_RESERVE || balance1 > MAX_RESERVE {
+                    revert(UniswapPairError::Overflow)
+                }
+
+                // The invariant is checked on balances with the fee taken out of
+                // the input amounts, scaled by the fee denominator to stay integral.
+                // The factory keeps the fee below the denominator.
+                let fee = U256::from(IUniswapV2FactoryRef::new(pair.factory).fees());
+                let fee_denominator = U256::from(FEE_DENOMINATOR);
+                let balance0_adjusted =
+                    U256::from(balance0) * fee_denominator - U256::from(amount0_in) * fee;
+                let balance1_adjusted =
+                    U256::from(balance1) * fee_denominator - U256::from(amount1_in) * fee;
+                let k_after = balance0_adjusted * balance1_adjusted;
+                let k_before = U256::from(reserve0)
+                    * U256::from(reserve1)
+                    * (fee_denominator * fee_denominator);
+                if k_after < k_before {
+                    revert(UniswapPairError::K)
+                }
+
+                pair.update(balance0, balance1).unwrap_or_else(revert);
+                pair.env().emit_event(Swap {
+                    sender: pair.env().caller(),
+                    amount0_in,
+                    amount1_in,
+                    amount0_out,
+                    amount1_out,
+                    to,
+                });
+                Ok(())
+            })
+        }
+
+        #[ink(message)]
+        fn skim(&mut self, to: AccountId) -> UniswapPairResult<()> {
+            self.lock(|pair| {
+                let (token0, token1) = (pair.token0, pair.token1);
+                let excess0 = pair
+                    .token_balance(token0)
+                    .checked_sub(pair.reserve0)
+                    .ok_or(UniswapPairError::Overflow)?;
+                let excess1 = pair
+                    .token_balance(token1)
+                    .checked_sub(pair.reserve1)
+                    .ok_or(UniswapPairError::Overflow)?;
+                pair.safe_transfer(token0, to, excess0)?;
+                // The first transfer went through, so this one must revert.
+                pair.safe_transfer(token1, to, excess1)
+                    .unwrap_or_else(revert);
+                Ok(())
+            })
+        }
+
+        #[ink(message)]
+        fn sync(&mut self) -> UniswapPairResult<()> {
+            self.lock(|pair| {
+                let balance0 = pair.token_balance(pair.token0);
+                let balance1 = pair.token_balance(pair.token1);
+                pair.update(balance0, balance1)
+            })
+        }
+
+        #[ink(message)]
+        fn initialize(&mut self, token0: AccountId, token1: AccountId) -> UniswapPairResult<()> {
+            if self.env().caller() != self.factory {
+                return Err(UniswapPairError::Forbidden);
+            }
+            self.token0 = token0;
+            self.token1 = token1;
+            Ok(())
+        }
+    }
+
+    impl Upgradeable for UniswapV2Pair {
+        #[ink(message)]
+        fn storage_version(&self) -> u32 {
+            self.storage_version
+        }
+
+        #[ink(message)]
+        fn upgrade(&mut self, code_hash: [u8; 32]) -> UpgradeResult<()> {
+            self.ensure_factory_owner()?;
+            ink_env::set_code_hash(&code_hash).map_err(|_| UpgradeError::CodeNotFound)?;
+            self.env().emit_event(Upgraded { code_hash });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn migrate(&mut self) -> UpgradeResult<()> {
+            self.ensure_factory_owner()?;
+            match self.storage_version {
+                STORAGE_VERSION => Ok(()),
+                version => Err(UpgradeError::UnknownStorageVersion(version)),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        /// Makes `call` from within a locked call on `pair`, on another
+        /// instance of it: like a re-entering call, that instance hasn't
+        /// seen the fields of `pair`, which are only written back once
+        /// its own message returns.
+        ///
+        /// Off-chain tests can't call across contracts, so this stands in
+        /// for a callee calling back into the pair. The
+        /// `uniswap_v2_reentrant_callee` contract makes those calls for real,
+        /// but can only be exercised against a chain, not in these tests.
+        fn reenter<T>(
+            pair: &mut UniswapV2Pair,
+            call: impl FnOnce(&mut UniswapV2Pair) -> UniswapPairResult<T>,
+        ) -> UniswapPairResult<UniswapPairResult<T>> {
+            let mut reentered = UniswapV2Pair::new(Hash::default());
+            pair.lock(|_| Ok(call(&mut reentered)))
+        }
+
+        fn bob() -> AccountId {
+            ink_env::test::default_accounts::<ink_env::DefaultEnvironment>().bob
+        }
+
+        #[ink::test]
+        fn reentrant_swap_is_locked() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            let result = reenter(&mut pair, |pair| pair.swap(1, 0, bob(), vec![1]));
+            assert_eq!(result, Ok(Err(UniswapPairError::Locked)));
+        }
+
+        #[ink::test]
+        fn reentrant_mint_is_locked() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            let result = reenter(&mut pair, |pair| pair.mint(bob()));
+            assert_eq!(result, Ok(Err(UniswapPairError::Locked)));
+        }
+
+        #[ink::test]
+        fn reentrant_burn_is_locked() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            let result = reenter(&mut pair, |pair| pair.burn(bob()));
+            assert_eq!(result, Ok(Err(UniswapPairError::Locked)));
+        }
+
+        #[ink::test]
+        fn reentrant_skim_is_locked() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            let result = reenter(&mut pair, |pair| pair.skim(bob()));
+            assert_eq!(result, Ok(Err(UniswapPairError::Locked)));
+        }
+
+        #[ink::test]
+        fn reentrant_sync_is_locked() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            let result = reenter(&mut pair, |pair| pair.sync());
+            assert_eq!(result, Ok(Err(UniswapPairError::Locked)));
+        }
+
+        #[ink::test]
+        fn lock_is_released() {
+            let mut pair = UniswapV2Pair::new(Hash::default());
+            assert_eq!(pair.lock(|_| Ok(())), Ok(()));
+            assert!(!is_locked());
+            assert_eq!(
+                pair.lock(|_| Err::<(), _>(UniswapPairError::K)),
+                Err(UniswapPairError::K)
+            );
+            assert!(!is_locked());
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! A flash swap callee that calls back into the pair paying it out,
+//! for testing that pairs refuse re-entering calls.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+/// The call a `ReentrantCallee` makes back into the pair,
+/// passed SCALE encoded as the `data` of the swap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Reentry {
+    Mint,
+    Burn,
+    Swap,
+    Skim,
+    Sync,
+}
+
+#[ink::contract]
+mod uniswap_v2_reentrant_callee {
+    use crate::Reentry;
+    use ink_prelude::vec::Vec;
+    use swap_traits::{IUniswapV2Callee, IUniswapV2PairRef, UniswapPairError};
+
+    #[ink(storage)]
+    pub struct ReentrantCallee {}
+
+    impl ReentrantCallee {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    impl Default for ReentrantCallee {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl IUniswapV2Callee for ReentrantCallee {
+        /// Makes the `Reentry` encoded in `data` into the calling pair,
+        /// panicking unless the pair answers that it is locked.
+        ///
+        /// `pallet-contracts` refuses the re-entering call before it reaches
+        /// the pair, as the pair doesn't allow re-entry, which also ends the
+        /// swap with a trap. The pair's lock only decides the outcome should
+        /// a call out of the pair ever allow re-entry.
+        #[ink(message)]
+        fn uniswap_v2_call(
+            &mut self,
+            _sender: AccountId,
+            _amount0: Balance,
+            _amount1: Balance,
+            data: Vec<u8>,
+        ) {
+            let reentry: Reentry =
+                scale::Decode::decode(&mut &data[..]).expect("data must be a Reentry");
+            let pair = IUniswapV2PairRef::new(self.env().caller());
+            let this = self.env().account_id();
+            let result = match reentry {
+                Reentry::Mint => pair.mint(this).map(drop),
+                Reentry::Burn => pair.burn(this).map(drop),
+                Reentry::Swap => pair.swap(0, 1, this, Vec::new()),
+                Reentry::Skim => pair.skim(this),
+                Reentry::Sync => pair.sync(),
+            };
+            assert_eq!(result, Err(UniswapPairError::Locked));
+        }
+    }
+}
@@ -0,0 +1,691 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod uniswap_v2_router {
+    use ink_prelude::vec::Vec;
+    use swap_traits::{
+        Erc20Ref, IUniswapV2FactoryRef, IUniswapV2PairRef, IUniswapV2Router, IWrappedNativeRef,
+        UniswapRouterError, UniswapRouterResult,
+    };
+    use uniswap_v2_library::{get_amount_out, get_amounts_in, get_amounts_out, quote, sort_tokens};
+
+    #[ink(storage)]
+    pub struct UniswapV2Router {
+        factory: AccountId,
+        wnative: AccountId,
+    }
+
+    impl UniswapV2Router {
+        #[ink(constructor)]
+        pub fn new(factory: AccountId, wnative: AccountId) -> Self {
+            Self { factory, wnative }
+        }
+
+        fn ensure(&self, deadline: Timestamp) -> UniswapRouterResult<()> {
+            if deadline < self.env().block_timestamp() {
+                return Err(UniswapRouterError::Expired);
+            }
+            Ok(())
+        }
+
+        /// Pays out `value` of the native currency,
+        /// panicking, and thereby reverting, if that fails.
+        fn transfer_native(&self, to: AccountId, value: Balance) {
+            self.env()
+                .transfer(to, value)
+                .unwrap_or_else(|error| panic!("failed to transfer native currency: {:?}", error))
+        }
+
+        /// Wraps `value` of the native currency the router holds
+        /// and sends it to `pair`.
+        fn wrap_to(&self, pair: AccountId, value: Balance) -> UniswapRouterResult<()> {
+            IWrappedNativeRef::new(self.wnative).deposit(value);
+            Erc20Ref::new(self.wnative).transfer(pair, value)?;
+            Ok(())
+        }
+
+        /// Unwraps `value` of the wrapped native token the router holds
+        /// and pays it out to `to`.
+        fn unwrap_to(&self, to: AccountId, value: Balance) -> UniswapRouterResult<()> {
+            IWrappedNativeRef::new(self.wnative).withdraw(value)?;
+            self.transfer_native(to, value);
+            Ok(())
+        }
+
+        /// Returns the pair of two tokens, as created by the factory.
+        fn get_pair(
+            &self,
+            token_a: AccountId,
+            token_b: AccountId,
+        ) -> UniswapRouterResult<IUniswapV2PairRef> {
+            sort_tokens(token_a, token_b)?;
+            IUniswapV2FactoryRef::new(self.factory)
+                .get_pair(token_a, token_b)
+                .map(IUniswapV2PairRef::new)
+                .ok_or(UniswapRouterError::PairNotFound)
+        }
+
+        /// Returns the pair of each hop along `path`.
+        fn get_path_pairs(
+            &self,
+            path: &[AccountId],
+        ) -> UniswapRouterResult<Vec<IUniswapV2PairRef>> {
+            if path.len() < 2 {
+                return Err(UniswapRouterError::InvalidPath);
+            }
+            path.windows(2)
+                .map(|hop| self.get_pair(hop[0], hop[1]))
+                .collect()
+        }
+
+        /// Returns the fee swaps pay, in thousandths, as set in the factory.
+        fn fees(&self) -> Balance {
+            IUniswapV2FactoryRef::new(self.factory).fees()
+        }
+
+        /// Returns the reserves of `token_a` and `token_b` in their `pair`.
+        fn get_reserves(
+            pair: &IUniswapV2PairRef,
+            token_a: AccountId,
+            token_b: AccountId,
+        ) -> UniswapRouterResult<(Balance, Balance)> {
+            let (token0, _) = sort_tokens(token_a, token_b)?;
+            let (reserve0, reserve1, _) = pair.get_reserves();
+            if token_a == token0 {
+                Ok((reserve0, reserve1))
+            } else {
+                Ok((reserve1, reserve0))
+            }
+        }
+
+        /// Returns the `(reserve_in, reserve_out)` of each hop along `path`,
+        /// given their `pairs`.
+        fn get_path_reserves(
+            path: &[AccountId],
+            pairs: &[IUniswapV2PairRef],
+        ) -> UniswapRouterResult<Vec<(Balance, Balance)>> {
+            path.windows(2)
+                .zip(pairs)
+                .map(|(hop, pair)| Self::get_reserves(pair, hop[0], hop[1]))
+                .collect()
+        }
+
+        /// Returns the amounts a swap of `amount_in` along `path` passes
+        /// through its `pairs`.
+        fn amounts_out(
+            &self,
+            amount_in: Balance,
+            path: &[AccountId],
+            pairs: &[IUniswapV2PairRef],
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            let reserves = Self::get_path_reserves(path, pairs)?;
+            Ok(get_amounts_out(amount_in, &reserves, self.fees())?)
+        }
+
+        /// Returns the amounts a swap along `path` must pass through its
+        /// `pairs` to end with `amount_out`.
+        fn amounts_in(
+            &self,
+            amount_out: Balance,
+            path: &[AccountId],
+            pairs: &[IUniswapV2PairRef],
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            let reserves = Self::get_path_reserves(path, pairs)?;
+            Ok(get_amounts_in(amount_out, &reserves, self.fees())?)
+        }
+
+        /// Swaps `amounts` along `path` through its `pairs`, where the first
+        /// amount must already have been sent to the first pair.
+        fn swap(
+            &self,
+            amounts: &[Balance],
+            path: &[AccountId],
+            pairs: &[IUniswapV2PairRef],
+            to: AccountId,
+        ) -> UniswapRouterResult<()> {
+            for (i, hop) in path.windows(2).enumerate() {
+                let (input, output) = (hop[0], hop[1]);
+                let (token0, _) = sort_tokens(input, output)?;
+                let amount_out = amounts[i + 1];
+                let (amount0_out, amount1_out) = if input == token0 {
+                    (0, amount_out)
+                } else {
+                    (amount_out, 0)
+                };
+                // Each hop pays out straight to the pair of the next one.
+                let hop_to = pairs.get(i + 1).map_or(to, |next| next.account_id());
+                pairs[i].swap(amount0_out, amount1_out, hop_to, Vec::new())?;
+            }
+            Ok(())
+        }
+
+        /// Swaps along `path` through its `pairs` whatever each pair received,
+        /// for tokens that take a fee on transfer. The input must already be
+        /// in the first pair.
+        fn swap_supporting_fee_on_transfer_tokens(
+            &self,
+            path: &[AccountId],
+            pairs: &[IUniswapV2PairRef],
+            to: AccountId,
+        ) -> UniswapRouterResult<()> {
+            let fees = self.fees();
+            for (i, hop) in path.windows(2).enumerate() {
+                let (input, output) = (hop[0], hop[1]);
+                let (token0, _) = sort_tokens(input, output)?;
+                let pair = &pairs[i];
+                let (reserve_in, reserve_out) = Self::get_reserves(pair, input, output)?;
+                let amount_in = Erc20Ref::new(input)
+                    .balance_of(pair.account_id())
+                    .saturating_sub(reserve_in);
+                let amount_out = get_amount_out(amount_in, reserve_in, reserve_out, fees)?;
+                let (amount0_out, amount1_out) = if input == token0 {
+                    (0, amount_out)
+                } else {
+                    (amount_out, 0)
+                };
+                let hop_to = pairs.get(i + 1).map_or(to, |next| next.account_id());
+                pair.swap(amount0_out, amount1_out, hop_to, Vec::new())?;
+            }
+            Ok(())
+        }
+
+        /// Computes how much of each token to deposit for liquidity,
+        /// creating their pair if needed.
+        fn calculate_liquidity(
+            &self,
+            token_a: AccountId,
+            token_b: AccountId,
+            amount_a_desired: Balance,
+            amount_b_desired: Balance,
+            amount_a_min: Balance,
+            amount_b_min: Balance,
+        ) -> UniswapRouterResult<(IUniswapV2PairRef, Balance, Balance)> {
+            let factory = IUniswapV2FactoryRef::new(self.factory);
+            let pair = match factory.get_pair(token_a, token_b) {
+                Some(pair) => pair,
+                None => factory.create_pair(token_a, token_b)?,
+            };
+            let pair = IUniswapV2PairRef::new(pair);
+
+            let (reserve_a, reserve_b) = Self::get_reserves(&pair, token_a, token_b)?;
+            if reserve_a == 0 && reserve_b == 0 {
+                return Ok((pair, amount_a_desired, amount_b_desired));
+            }
+            let amount_b_optimal = quote(amount_a_desired, reserve_a, reserve_b)?;
+            if amount_b_optimal <= amount_b_desired {
+                if amount_b_optimal < amount_b_min {
+                    return Err(UniswapRouterError::InsufficientBAmount);
+                }
+                Ok((pair, amount_a_desired, amount_b_optimal))
+            } else {
+                let amount_a_optimal = quote(amount_b_desired, reserve_b, reserve_a)?;
+                debug_assert!(amount_a_optimal <= amount_a_desired);
+                if amount_a_optimal < amount_a_min {
+                    return Err(UniswapRouterError::InsufficientAAmount);
+                }
+                Ok((pair, amount_a_optimal, amount_b_desired))
+            }
+        }
+    }
+
+    /// Reverts the call with `error` as a `UniswapRouterError`.
+    fn revert<E: Into<UniswapRouterError>, T>(error: E) -> T {
+        swap_traits::revert::<UniswapRouterError, T>(error.into())
+    }
+
+    impl IUniswapV2Router for UniswapV2Router {
+        #[ink(message)]
+        fn factory(&self) -> AccountId {
+            self.factory
+        }
+
+        #[ink(message)]
+        fn wnative(&self) -> AccountId {
+            self.wnative
+        }
+
+        #[ink(message)]
+        fn add_liquidity(
+            &mut self,
+            token_a: AccountId,
+            token_b: AccountId,
+            amount_a_desired: Balance,
+            amount_b_desired: Balance,
+            amount_a_min: Balance,
+            amount_b_min: Balance,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<(Balance, Balance, Balance)> {
+            self.ensure(deadline)?;
+            let (pair, amount_a, amount_b) = self.calculate_liquidity(
+                token_a,
+                token_b,
+                amount_a_desired,
+                amount_b_desired,
+                amount_a_min,
+                amount_b_min,
+            )?;
+
+            // The pair may have just been created, so errors revert from here on.
+            let caller = self.env().caller();
+            Erc20Ref::new(token_a)
+                .transfer_from(caller, pair.account_id(), amount_a)
+                .unwrap_or_else(revert);
+            Erc20Ref::new(token_b)
+                .transfer_from(caller, pair.account_id(), amount_b)
+                .unwrap_or_else(revert);
+            let liquidity = pair.mint(to).unwrap_or_else(revert);
+            Ok((amount_a, amount_b, liquidity))
+        }
+
+        #[ink(message)]
+        fn remove_liquidity(
+            &mut self,
+            token_a: AccountId,
+            token_b: AccountId,
+            liquidity: Balance,
+            amount_a_min: Balance,
+            amount_b_min: Balance,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<(Balance, Balance)> {
+            self.ensure(deadline)?;
+            let pair = self.get_pair(token_a, token_b)?;
+
+            let caller = self.env().caller();
+            pair.transfer_from(caller, pair.account_id(), liquidity)?;
+            let (amount0, amount1) = pair.burn(to).unwrap_or_else(revert);
+            let (token0, _) = sort_tokens(token_a, token_b).unwrap_or_else(revert);
+            let (amount_a, amount_b) = if token_a == token0 {
+                (amount0, amount1)
+            } else {
+                (amount1, amount0)
+            };
+            if amount_a < amount_a_min {
+                return revert(UniswapRouterError::InsufficientAAmount);
+            }
+            if amount_b < amount_b_min {
+                return revert(UniswapRouterError::InsufficientBAmount);
+            }
+            Ok((amount_a, amount_b))
+        }
+
+        #[ink(message)]
+        fn swap_exact_tokens_for_tokens(
+            &mut self,
+            amount_in: Balance,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline)?;
+            let pairs = self.get_path_pairs(&path)?;
+            let amounts = self.amounts_out(amount_in, &path, &pairs)?;
+            if amounts[amounts.len() - 1] < amount_out_min {
+                return Err(UniswapRouterError::InsufficientOutputAmount);
+            }
+
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amounts[0],
+            )?;
+            // The input is in the first pair, so errors revert from here on.
+            self.swap(&amounts, &path, &pairs, to)
+                .unwrap_or_else(revert);
+            Ok(amounts)
+        }
+
+        #[ink(message)]
+        fn get_amounts_out(
+            &self,
+            amount_in: Balance,
+            path: Vec<AccountId>,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.amounts_out(amount_in, &path, &self.get_path_pairs(&path)?)
+        }
+
+        #[ink(message)]
+        fn swap_tokens_for_exact_tokens(
+            &mut self,
+            amount_out: Balance,
+            amount_in_max: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline)?;
+            let pairs = self.get_path_pairs(&path)?;
+            let amounts = self.amounts_in(amount_out, &path, &pairs)?;
+            if amounts[0] > amount_in_max {
+                return Err(UniswapRouterError::ExcessiveInputAmount(amounts[0]));
+            }
+
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amounts[0],
+            )?;
+            // The input is in the first pair, so errors revert from here on.
+            self.swap(&amounts, &path, &pairs, to)
+                .unwrap_or_else(revert);
+            Ok(amounts)
+        }
+
+        #[ink(message)]
+        fn get_amounts_in(
+            &self,
+            amount_out: Balance,
+            path: Vec<AccountId>,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.amounts_in(amount_out, &path, &self.get_path_pairs(&path)?)
+        }
+
+        // Payable messages revert on every error,
+        // so that the transferred value is returned too.
+        #[ink(message, payable)]
+        fn add_liquidity_native(
+            &mut self,
+            token: AccountId,
+            amount_token_desired: Balance,
+            amount_token_min: Balance,
+            amount_native_min: Balance,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<(Balance, Balance, Balance)> {
+            self.ensure(deadline).unwrap_or_else(revert);
+            let amount_native_desired = self.env().transferred_value();
+            let (pair, amount_token, amount_native) = self
+                .calculate_liquidity(
+                    token,
+                    self.wnative,
+                    amount_token_desired,
+                    amount_native_desired,
+                    amount_token_min,
+                    amount_native_min,
+                )
+                .unwrap_or_else(revert);
+
+            let caller = self.env().caller();
+            Erc20Ref::new(token)
+                .transfer_from(caller, pair.account_id(), amount_token)
+                .unwrap_or_else(revert);
+            self.wrap_to(pair.account_id(), amount_native)
+                .unwrap_or_else(revert);
+            let liquidity = pair.mint(to).unwrap_or_else(revert);
+            if amount_native_desired > amount_native {
+                self.transfer_native(caller, amount_native_desired - amount_native);
+            }
+            Ok((amount_token, amount_native, liquidity))
+        }
+
+        #[ink(message)]
+        fn remove_liquidity_native(
+            &mut self,
+            token: AccountId,
+            liquidity: Balance,
+            amount_token_min: Balance,
+            amount_native_min: Balance,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<(Balance, Balance)> {
+            let (amount_token, amount_native) = self.remove_liquidity(
+                token,
+                self.wnative,
+                liquidity,
+                amount_token_min,
+                amount_native_min,
+                self.env().account_id(),
+                deadline,
+            )?;
+
+            // The liquidity is burned, so errors revert from here on.
+            Erc20Ref::new(token)
+                .transfer(to, amount_token)
+                .unwrap_or_else(revert);
+            self.unwrap_to(to, amount_native).unwrap_or_else(revert);
+            Ok((amount_token, amount_native))
+        }
+
+        #[ink(message, payable)]
+        fn swap_exact_native_for_tokens(
+            &mut self,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline).unwrap_or_else(revert);
+            if path.first() != Some(&self.wnative) {
+                revert(UniswapRouterError::InvalidPath)
+            }
+            let pairs = self.get_path_pairs(&path).unwrap_or_else(revert);
+            let amounts = self
+                .amounts_out(self.env().transferred_value(), &path, &pairs)
+                .unwrap_or_else(revert);
+            if amounts[amounts.len() - 1] < amount_out_min {
+                revert(UniswapRouterError::InsufficientOutputAmount)
+            }
+
+            self.wrap_to(pairs[0].account_id(), amounts[0])
+                .unwrap_or_else(revert);
+            self.swap(&amounts, &path, &pairs, to)
+                .unwrap_or_else(revert);
+            Ok(amounts)
+        }
+
+        #[ink(message)]
+        fn swap_tokens_for_exact_native(
+            &mut self,
+            amount_out: Balance,
+            amount_in_max: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline)?;
+            if path.last() != Some(&self.wnative) {
+                return Err(UniswapRouterError::InvalidPath);
+            }
+            let pairs = self.get_path_pairs(&path)?;
+            let amounts = self.amounts_in(amount_out, &path, &pairs)?;
+            if amounts[0] > amount_in_max {
+                return Err(UniswapRouterError::ExcessiveInputAmount(amounts[0]));
+            }
+
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amounts[0],
+            )?;
+            // The input is in the first pair, so errors revert from here on.
+            self.swap(&amounts, &path, &pairs, self.env().account_id())
+                .unwrap_or_else(revert);
+            self.unwrap_to(to, amount_out).unwrap_or_else(revert);
+            Ok(amounts)
+        }
+
+        #[ink(message)]
+        fn swap_exact_tokens_for_native(
+            &mut self,
+            amount_in: Balance,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline)?;
+            if path.last() != Some(&self.wnative) {
+                return Err(UniswapRouterError::InvalidPath);
+            }
+            let pairs = self.get_path_pairs(&path)?;
+            let amounts = self.amounts_out(amount_in, &path, &pairs)?;
+            let amount_out = amounts[amounts.len() - 1];
+            if amount_out < amount_out_min {
+                return Err(UniswapRouterError::InsufficientOutputAmount);
+            }
+
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amounts[0],
+            )?;
+            // The input is in the first pair, so errors revert from here on.
+            self.swap(&amounts, &path, &pairs, self.env().account_id())
+                .unwrap_or_else(revert);
+            self.unwrap_to(to, amount_out).unwrap_or_else(revert);
+            Ok(amounts)
+        }
+
+        #[ink(message, payable)]
+        fn swap_native_for_exact_tokens(
+            &mut self,
+            amount_out: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Vec<Balance>> {
+            self.ensure(deadline).unwrap_or_else(revert);
+            if path.first() != Some(&self.wnative) {
+                revert(UniswapRouterError::InvalidPath)
+            }
+            let pairs = self.get_path_pairs(&path).unwrap_or_else(revert);
+            let amounts = self
+                .amounts_in(amount_out, &path, &pairs)
+                .unwrap_or_else(revert);
+            let amount_in_max = self.env().transferred_value();
+            if amounts[0] > amount_in_max {
+                revert(UniswapRouterError::ExcessiveInputAmount(amounts[0]))
+            }
+
+            self.wrap_to(pairs[0].account_id(), amounts[0])
+                .unwrap_or_else(revert);
+            self.swap(&amounts, &path, &pairs, to)
+                .unwrap_or_else(revert);
+            if amount_in_max > amounts[0] {
+                self.transfer_native(self.env().caller(), amount_in_max - amounts[0]);
+            }
+            Ok(amounts)
+        }
+
+        #[ink(message)]
+        fn remove_liquidity_native_supporting_fee_on_transfer_tokens(
+            &mut self,
+            token: AccountId,
+            liquidity: Balance,
+            amount_token_min: Balance,
+            amount_native_min: Balance,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<Balance> {
+            let (_, amount_native) = self.remove_liquidity(
+                token,
+                self.wnative,
+                liquidity,
+                amount_token_min,
+                amount_native_min,
+                self.env().account_id(),
+                deadline,
+            )?;
+
+            // The liquidity is burned, so errors revert from here on.
+            let token = Erc20Ref::new(token);
+            token
+                .transfer(to, token.balance_of(self.env().account_id()))
+                .unwrap_or_else(revert);
+            self.unwrap_to(to, amount_native).unwrap_or_else(revert);
+            Ok(amount_native)
+        }
+
+        #[ink(message)]
+        fn swap_exact_tokens_for_tokens_supporting_fee_on_transfer_tokens(
+            &mut self,
+            amount_in: Balance,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<()> {
+            self.ensure(deadline)?;
+            let pairs = self.get_path_pairs(&path)?;
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amount_in,
+            )?;
+
+            // The input is in the first pair, so errors revert from here on.
+            let token_out = Erc20Ref::new(path[path.len() - 1]);
+            let balance_before = token_out.balance_of(to);
+            self.swap_supporting_fee_on_transfer_tokens(&path, &pairs, to)
+                .unwrap_or_else(revert);
+            if token_out.balance_of(to).saturating_sub(balance_before) < amount_out_min {
+                revert(UniswapRouterError::InsufficientOutputAmount)
+            }
+            Ok(())
+        }
+
+        #[ink(message, payable)]
+        fn swap_exact_native_for_tokens_supporting_fee_on_transfer_tokens(
+            &mut self,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<()> {
+            self.ensure(deadline).unwrap_or_else(revert);
+            if path.first() != Some(&self.wnative) {
+                revert(UniswapRouterError::InvalidPath)
+            }
+            let pairs = self.get_path_pairs(&path).unwrap_or_else(revert);
+            self.wrap_to(pairs[0].account_id(), self.env().transferred_value())
+                .unwrap_or_else(revert);
+
+            let token_out = Erc20Ref::new(path[path.len() - 1]);
+            let balance_before = token_out.balance_of(to);
+            self.swap_supporting_fee_on_transfer_tokens(&path, &pairs, to)
+                .unwrap_or_else(revert);
+            if token_out.balance_of(to).saturating_sub(balance_before) < amount_out_min {
+                revert(UniswapRouterError::InsufficientOutputAmount)
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn swap_exact_tokens_for_native_supporting_fee_on_transfer_tokens(
+            &mut self,
+            amount_in: Balance,
+            amount_out_min: Balance,
+            path: Vec<AccountId>,
+            to: AccountId,
+            deadline: Timestamp,
+        ) -> UniswapRouterResult<()> {
+            self.ensure(deadline)?;
+            if path.last() != Some(&self.wnative) {
+                return Err(UniswapRouterError::InvalidPath);
+            }
+            let pairs = self.get_path_pairs(&path)?;
+            Erc20Ref::new(path[0]).transfer_from(
+                self.env().caller(),
+                pairs[0].account_id(),
+                amount_in,
+            )?;
+
+            // The input is in the first pair, so errors revert from here on.
+            let router = self.env().account_id();
+            let wnative = Erc20Ref::new(self.wnative);
+            let balance_before = wnative.balance_of(router);
+            self.swap_supporting_fee_on_transfer_tokens(&path, &pairs, router)
+                .unwrap_or_else(revert);
+            let amount_out = wnative.balance_of(router).saturating_sub(balance_before);
+            if amount_out < amount_out_min {
+                revert(UniswapRouterError::InsufficientOutputAmount)
+            }
+            self.unwrap_to(to, amount_out).unwrap_or_else(revert);
+            Ok(())
+        }
+    }
+}
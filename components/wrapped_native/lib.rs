@@ -0,0 +1,297 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ink_lang as ink;
+
+#[ink::contract]
+mod wrapped_native {
+    use ink_prelude::string::String;
+    use ink_storage::traits::SpreadAllocate;
+    use swap_traits::{Erc20, Erc20Error, Erc20Metadata, Erc20Result, IWrappedNative};
+    use uniswap_v2_erc20::Erc20Data;
+
+    /// The native currency, e.g. DOT or ASTR, as an `Erc20` token.
+    ///
+    /// Every token is backed by a unit of the native currency
+    /// held by this contract.
+    #[ink(storage)]
+    #[derive(SpreadAllocate)]
+    pub struct WrappedNative {
+        erc20: Erc20Data,
+        name: String,
+        symbol: String,
+        decimals: u8,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Deposit {
+        #[ink(topic)]
+        to: AccountId,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Withdrawal {
+        #[ink(topic)]
+        from: AccountId,
+        value: Balance,
+    }
+
+    impl WrappedNative {
+        /// Creates the token, e.g. as "Wrapped DOT", "WDOT" with 10 decimals,
+        /// which should match those of the native currency.
+        #[ink(constructor)]
+        pub fn new(name: String, symbol: String, decimals: u8) -> Self {
+            ink_lang::utils::initialize_contract(|contract: &mut Self| {
+                contract.name = name;
+                contract.symbol = symbol;
+                contract.decimals = decimals;
+            })
+        }
+
+        fn transfer_from_to(
+            &mut self,
+            from: &AccountId,
+            to: &AccountId,
+            value: Balance,
+        ) -> Erc20Result<()> {
+            self.erc20.transfer_from_to(from, to, value)?;
+            self.env().emit_event(Transfer {
+                from: Some(*from),
+                to: Some(*to),
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    impl IWrappedNative for WrappedNative {
+        #[ink(message, payable)]
+        fn deposit(&mut self) {
+            let caller = self.env().caller();
+            let value = self.env().transferred_value();
+            // Can't overflow, as the total supply is backed by native currency.
+            self.erc20
+                .mint(&caller, value)
+                .expect("the total supply fits in a Balance");
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(caller),
+                value,
+            });
+            self.env().emit_event(Deposit { to: caller, value });
+        }
+
+        #[ink(message)]
+        fn withdraw(&mut self, value: Balance) -> Erc20Result<()> {
+            let caller = self.env().caller();
+            if self.erc20.balance_of(&caller) < value {
+                return Err(Erc20Error::InsufficientBalance);
+            }
+            self.erc20.burn(&caller, value)?;
+            self.env().emit_event(Transfer {
+                from: Some(caller),
+                to: None,
+                value,
+            });
+            self.env().emit_event(Withdrawal {
+                from: caller,
+                value,
+            });
+            // Panics, and thereby reverts the burn, if the transfer fails.
+            self.env()
+                .transfer(caller, value)
+                .unwrap_or_else(|error| panic!("failed to pay out withdrawal: {:?}", error));
+            Ok(())
+        }
+    }
+
+    impl Erc20Metadata for WrappedNative {
+        #[ink(message)]
+        fn name(&self) -> String {
+            self.name.clone()
+        }
+
+        #[ink(message)]
+        fn symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        #[ink(message)]
+        fn decimals(&self) -> u8 {
+            self.decimals
+        }
+    }
+
+    impl Erc20 for WrappedNative {
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.erc20.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.erc20.balance_of(&owner)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.erc20.allowance(&owner, &spender)
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: Balance) -> Erc20Result<()> {
+            let from = self.env().caller();
+            self.transfer_from_to(&from, &to, value)
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
+            let owner = self.env().caller();
+            self.erc20.approve(&owner, &spender, value);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+        ) -> Erc20Result<()> {
+            let caller = self.env().caller();
+            if self.erc20.allowance(&from, &caller) < value {
+                return Err(Erc20Error::InsufficientAllowance);
+            }
+
+            self.transfer_from_to(&from, &to, value)?;
+            if let Some(allowance) = self.erc20.spend_allowance(&from, &caller, value)? {
+                self.env().emit_event(Approval {
+                    owner: from,
+                    spender: caller,
+                    value: allowance,
+                });
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.erc20.increase_allowance(&owner, &spender, value)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: allowance,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(&mut self, spender: AccountId, value: Balance) -> Erc20Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.erc20.decrease_allowance(&owner, &spender, value)?;
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: allowance,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink_lang as ink;
+
+        type Environment = ink_env::DefaultEnvironment;
+
+        fn wrapped_native() -> WrappedNative {
+            WrappedNative::new("Wrapped DOT".into(), "WDOT".into(), 10)
+        }
+
+        fn accounts() -> ink_env::test::DefaultAccounts<Environment> {
+            ink_env::test::default_accounts::<Environment>()
+        }
+
+        /// Deposits `value` as the caller. Off-chain, the transferred value
+        /// isn't moved, so this also adds it to the contract's balance.
+        fn deposit(wrapped_native: &mut WrappedNative, value: Balance) {
+            let contract = ink_env::test::callee::<Environment>();
+            let balance = ink_env::test::get_account_balance::<Environment>(contract).unwrap();
+            ink_env::test::set_account_balance::<Environment>(contract, balance + value);
+            ink_env::test::set_value_transferred::<Environment>(value);
+            wrapped_native.deposit();
+            ink_env::test::set_value_transferred::<Environment>(0);
+        }
+
+        #[ink::test]
+        fn deposit_mints_transferred_value() {
+            let mut wrapped_native = wrapped_native();
+            let alice = accounts().alice;
+            deposit(&mut wrapped_native, 100);
+            assert_eq!(wrapped_native.balance_of(alice), 100);
+            assert_eq!(wrapped_native.total_supply(), 100);
+            // The `Transfer` from nowhere and the `Deposit`.
+            assert_eq!(ink_env::test::recorded_events().count(), 2);
+        }
+
+        #[ink::test]
+        fn withdraw_burns_and_pays_out() {
+            let mut wrapped_native = wrapped_native();
+            let alice = accounts().alice;
+            deposit(&mut wrapped_native, 100);
+            let native_balance = ink_env::test::get_account_balance::<Environment>(alice).unwrap();
+
+            assert_eq!(wrapped_native.withdraw(40), Ok(()));
+            assert_eq!(wrapped_native.balance_of(alice), 60);
+            assert_eq!(wrapped_native.total_supply(), 60);
+            assert_eq!(
+                ink_env::test::get_account_balance::<Environment>(alice),
+                Ok(native_balance + 40)
+            );
+            assert_eq!(ink_env::test::recorded_events().count(), 4);
+        }
+
+        #[ink::test]
+        fn withdraw_more_than_balance_fails() {
+            let mut wrapped_native = wrapped_native();
+            let alice = accounts().alice;
+            deposit(&mut wrapped_native, 100);
+            let native_balance = ink_env::test::get_account_balance::<Environment>(alice).unwrap();
+
+            assert_eq!(
+                wrapped_native.withdraw(101),
+                Err(Erc20Error::InsufficientBalance)
+            );
+            assert_eq!(wrapped_native.balance_of(alice), 100);
+            assert_eq!(
+                ink_env::test::get_account_balance::<Environment>(alice),
+                Ok(native_balance)
+            );
+        }
+    }
+}